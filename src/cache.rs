@@ -0,0 +1,350 @@
+//! 성공한 단계의 결과 캐시 (`.rusky/cache`)
+//!
+//! 단계가 보는 입력을 모두 적은 목록을 만들고, 그 목록의 git object id를 키로 씁니다.
+//! 같은 키로 성공한 적이 있으면 단계를 다시 실행하지 않습니다.
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::path::Path;
+use tokio::fs;
+use tokio::process::Command;
+
+use crate::config::{deserialize_one_or_many, HookCommand};
+use crate::context::HookContext;
+use crate::git::Git;
+
+/// 캐시 결과를 저장하는 디렉토리 (저장소 루트 기준)
+const CACHE_DIR: &str = ".rusky/cache";
+
+/// 키 목록의 형식이 바뀌면 올려서 이전 결과를 무효화
+const KEY_FORMAT: &str = "rusky-cache-v1";
+
+/// 단계의 `cache` 설정 (`true` 또는 키에 더할 입력)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// 값이 바뀌면 다시 실행할 환경 변수
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub env: Vec<String>,
+    /// 내용이 바뀌면 다시 실행할 파일 (도구 설정, lock 파일 등)
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub files: Vec<String>,
+    /// 출력이 바뀌면 다시 실행할 명령어 (`eslint --version` 등)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// 이 버전이 모르는 설정 (키에 넣지 않고 경고만 하며, 저장할 때 보존)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// `true`/`false`와 `{ "env": [...], ... }` 두 가지 표기를 모두 허용
+pub fn deserialize_cache<'de, D>(deserializer: D) -> Result<Option<CacheConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Cache {
+        Enabled(bool),
+        Config(CacheConfig),
+    }
+
+    Ok(match Option::<Cache>::deserialize(deserializer)? {
+        None | Some(Cache::Enabled(false)) => None,
+        Some(Cache::Enabled(true)) => Some(CacheConfig::default()),
+        Some(Cache::Config(config)) => Some(config),
+    })
+}
+
+/// 추가 입력이 없으면 `true`로 저장
+pub fn serialize_cache<S>(cache: &Option<CacheConfig>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match cache {
+        Some(config) if *config == CacheConfig::default() => serializer.serialize_bool(true),
+        _ => cache.serialize(serializer),
+    }
+}
+
+/// 한 번의 실행에서 캐시를 사용한 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CacheStats {
+    /// 이전 결과를 재사용해 건너뛴 단계 수
+    pub hits: usize,
+    /// 캐시를 켰지만 실행해야 했던 단계 수
+    pub misses: usize,
+}
+
+impl CacheStats {
+    /// 실행 요약에 붙일 설명 (캐시를 쓴 단계가 없으면 None)
+    pub fn summary(&self) -> Option<String> {
+        if self.hits + self.misses == 0 {
+            return None;
+        }
+        Some(format!(
+            "cache: {} hit(s), {} miss(es)",
+            self.hits, self.misses
+        ))
+    }
+}
+
+pub struct ResultCache;
+
+impl ResultCache {
+    /// 단계의 캐시 키
+    ///
+    /// 단계 설정, hook 인자와 표준 입력, 인자로 받은 파일의 내용, `cache`에 지정한
+    /// 환경 변수·파일·버전 명령어 출력, 그리고 단계가 보는 staged 내용으로 만듭니다.
+    /// `glob` 단계는 일치하는 파일의 blob만, 그 밖의 단계는 index 전체 tree를 씁니다.
+    /// `stash` 없이 실행하는 hook은 작업 트리의 unstaged 변경도 키에 넣습니다.
+    pub async fn key(
+        context: &HookContext,
+        step: &HookCommand,
+        config: &CacheConfig,
+        files: Option<&[String]>,
+        stash: bool,
+    ) -> Result<String> {
+        let mut inputs = vec![
+            KEY_FORMAT.to_string(),
+            format!("rusky {}", env!("CARGO_PKG_VERSION")),
+            format!("hook {}", context.hook_name),
+            format!("step {}", serde_json::to_string(step)?),
+        ];
+
+        for arg in &context.args {
+            inputs.push(format!("arg {}", arg));
+            // commit-msg의 메시지 파일처럼 인자로 받은 파일은 내용까지 비교
+            if Path::new(arg).is_file() {
+                inputs.push(format!("arg-file {}", hash_file(arg).await?));
+            }
+        }
+        if let Some(stdin) = &context.stdin {
+            inputs.push(format!("stdin {}", Git::hash_object(stdin).await?));
+        }
+
+        for name in &config.env {
+            let value = std::env::var(name).unwrap_or_default();
+            inputs.push(format!("env {}={}", name, value));
+        }
+        for file in &config.files {
+            let hash = if Path::new(file).is_file() {
+                hash_file(file).await?
+            } else {
+                "missing".to_string()
+            };
+            inputs.push(format!("file {} {}", file, hash));
+        }
+        if let Some(version) = &config.version {
+            let output = Command::new("sh").arg("-c").arg(version).output().await?;
+            let mut printed = output.stdout;
+            printed.extend(output.stderr);
+            inputs.push(format!(
+                "version {} {}",
+                output.status.code().unwrap_or(-1),
+                Git::hash_object(&printed).await?
+            ));
+        }
+
+        match files {
+            Some(files) => {
+                for (path, object) in Git::get_staged_objects(files).await? {
+                    inputs.push(format!("staged {} {}", object, path));
+                }
+            }
+            None => inputs.push(format!("tree {}", Git::write_tree().await?)),
+        }
+        if !stash {
+            let patch = Git::get_unstaged_patch().await?;
+            if !patch.is_empty() {
+                inputs.push(format!("unstaged {}", Git::hash_object(&patch).await?));
+            }
+        }
+
+        Git::hash_object(inputs.join("\n").as_bytes()).await
+    }
+
+    /// 같은 키로 성공한 결과가 있는지 확인
+    pub fn contains(key: &str) -> bool {
+        Path::new(CACHE_DIR).join(key).is_file()
+    }
+
+    /// 단계가 성공했음을 기록
+    pub async fn store(key: &str, step_name: &str) -> Result<()> {
+        let cache_dir = Path::new(CACHE_DIR);
+        if !cache_dir.exists() {
+            fs::create_dir_all(cache_dir).await?;
+            // 실행 결과는 각자의 것이므로 커밋하지 않음
+            fs::write(cache_dir.join(".gitignore"), "*\n").await?;
+        }
+
+        fs::write(cache_dir.join(key), format!("{}\n", step_name)).await?;
+        Ok(())
+    }
+
+    /// 저장된 결과를 모두 지우고 지운 결과 수 반환
+    pub async fn clear() -> Result<usize> {
+        let cache_dir = Path::new(CACHE_DIR);
+        if !cache_dir.exists() {
+            return Ok(0);
+        }
+
+        let mut removed = 0;
+        let mut entries = fs::read_dir(cache_dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_name() != ".gitignore" {
+                removed += 1;
+            }
+        }
+        fs::remove_dir_all(cache_dir).await?;
+
+        Ok(removed)
+    }
+}
+
+/// 작업 트리에 있는 파일 내용의 object id
+async fn hash_file(path: &str) -> Result<String> {
+    Git::hash_object(&fs::read(path).await?).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    async fn git(args: &[&str]) {
+        let output = Command::new("git").args(args).output().await.unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn test_cache_config_notations() {
+        #[derive(Deserialize, Serialize)]
+        struct Step {
+            #[serde(
+                default,
+                skip_serializing_if = "Option::is_none",
+                deserialize_with = "deserialize_cache",
+                serialize_with = "serialize_cache"
+            )]
+            cache: Option<CacheConfig>,
+        }
+
+        let parse = |json: &str| serde_json::from_str::<Step>(json).unwrap().cache;
+        assert_eq!(parse(r#"{}"#), None);
+        assert_eq!(parse(r#"{ "cache": false }"#), None);
+        assert_eq!(parse(r#"{ "cache": true }"#), Some(CacheConfig::default()));
+        assert_eq!(
+            parse(r#"{ "cache": { "env": "NODE_ENV", "files": ["package-lock.json"] } }"#),
+            Some(CacheConfig {
+                env: vec!["NODE_ENV".to_string()],
+                files: vec!["package-lock.json".to_string()],
+                version: None,
+                extra: Map::new(),
+            })
+        );
+
+        // 모르는 설정은 설정을 읽지 못하게 하지 않고 그대로 보존
+        let cache = parse(r#"{ "cache": { "envs": ["CI"] } }"#).unwrap();
+        assert_eq!(cache.extra["envs"], serde_json::json!(["CI"]));
+        let saved = serde_json::to_string(&Step { cache: Some(cache) }).unwrap();
+        assert_eq!(saved, r#"{"cache":{"envs":["CI"]}}"#);
+
+        let saved = serde_json::to_string(&Step {
+            cache: Some(CacheConfig::default()),
+        })
+        .unwrap();
+        assert_eq!(saved, r#"{"cache":true}"#);
+    }
+
+    #[test]
+    fn test_stats_summary() {
+        assert_eq!(CacheStats::default().summary(), None);
+        let stats = CacheStats { hits: 2, misses: 1 };
+        assert_eq!(stats.summary().unwrap(), "cache: 2 hit(s), 1 miss(es)");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_key_follows_inputs() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("a.rs", "one\n").await.unwrap();
+        fs::write("b.rs", "one\n").await.unwrap();
+        fs::write("tool.json", "{}\n").await.unwrap();
+        git(&["add", "a.rs", "b.rs"]).await;
+
+        let context = HookContext::new("pre-commit", &[]);
+        let step = HookCommand::new("lint".to_string(), "lint".to_string());
+        let config = CacheConfig {
+            files: vec!["tool.json".to_string()],
+            ..CacheConfig::default()
+        };
+        let only_a = vec!["a.rs".to_string()];
+        let key = |stash| ResultCache::key(&context, &step, &config, Some(&only_a), stash);
+        let tree_key = || ResultCache::key(&context, &step, &config, None, true);
+
+        let first = key(true).await.unwrap();
+        let first_tree = tree_key().await.unwrap();
+        assert_eq!(first, key(true).await.unwrap());
+
+        // 다른 staged 파일은 glob 단계의 키에 영향이 없지만 index 전체 tree는 바뀜
+        fs::write("b.rs", "two\n").await.unwrap();
+        git(&["add", "b.rs"]).await;
+        assert_eq!(first, key(true).await.unwrap());
+        assert_ne!(first_tree, tree_key().await.unwrap());
+
+        // 단계가 보는 파일, 추가 입력 파일과 단계 설정이 바뀌면 키도 바뀜
+        fs::write("a.rs", "two\n").await.unwrap();
+        assert_eq!(first, key(true).await.unwrap());
+        assert_ne!(first, key(false).await.unwrap());
+        git(&["add", "a.rs"]).await;
+        let second = key(true).await.unwrap();
+        assert_ne!(first, second);
+
+        fs::write("tool.json", "{ \"strict\": true }\n")
+            .await
+            .unwrap();
+        assert_ne!(second, key(true).await.unwrap());
+
+        let mut renamed = step.clone();
+        renamed.run = "lint --fix".to_string();
+        let other = ResultCache::key(&context, &renamed, &config, Some(&only_a), true);
+        assert_ne!(key(true).await.unwrap(), other.await.unwrap());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_store_and_clear() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        assert_eq!(ResultCache::clear().await.unwrap(), 0);
+        assert!(!ResultCache::contains("abc"));
+
+        ResultCache::store("abc", "lint").await.unwrap();
+        ResultCache::store("def", "test").await.unwrap();
+        assert!(ResultCache::contains("abc"));
+        assert!(Path::new(".rusky/cache/.gitignore").exists());
+
+        assert_eq!(ResultCache::clear().await.unwrap(), 2);
+        assert!(!ResultCache::contains("abc"));
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -0,0 +1,240 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use globset::Glob;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::Report;
+use crate::context::HookContext;
+use crate::git::Git;
+
+/// 브랜치 이름 규칙
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct BranchNameRules {
+    /// 브랜치 이름이 하나 이상 일치해야 하는 정규식 (비어 있으면 모두 허용)
+    pub allow: Vec<String>,
+    /// 브랜치 이름이 일치하면 안 되는 정규식
+    pub deny: Vec<String>,
+    /// 직접 commit하거나 push할 수 없는 브랜치 (glob 사용 가능, 이름 규칙은 적용하지 않음)
+    pub protected: Vec<String>,
+}
+
+impl Default for BranchNameRules {
+    fn default() -> Self {
+        Self {
+            allow: Vec::new(),
+            deny: Vec::new(),
+            protected: vec!["main".to_string(), "master".to_string()],
+        }
+    }
+}
+
+impl BranchNameRules {
+    /// `pre-push`에서는 push되는 브랜치를, 그 외 hook에서는 현재 브랜치를 검사
+    pub async fn check(&self, context: &HookContext) -> Result<Report> {
+        let mut report = Report::default();
+
+        if context.hook_name == "pre-push" {
+            for pushed in context.pushed_refs() {
+                let Some(branch) = pushed.remote_ref.strip_prefix("refs/heads/") else {
+                    continue;
+                };
+
+                if self.is_protected(branch)? {
+                    let action = if pushed.is_delete() { "deleting" } else { "pushing directly to" };
+                    report.error(format!(
+                        "{} {} protected branch \"{}\" is not allowed",
+                        "✖".red(),
+                        action,
+                        branch
+                    ));
+                } else if !pushed.is_delete() {
+                    // 잘못된 이름의 원격 브랜치를 지우는 것은 허용
+                    self.check_name(branch, &mut report)?;
+                }
+            }
+            return Ok(report);
+        }
+
+        let Some(branch) = Git::get_current_branch().await? else {
+            report.info(format!("{}", "○ skipped (detached HEAD)".dimmed()));
+            return Ok(report);
+        };
+
+        if self.is_protected(&branch)? {
+            report.error(format!(
+                "{} committing directly to protected branch \"{}\" is not allowed; create a branch first",
+                "✖".red(),
+                branch
+            ));
+        } else {
+            self.check_name(&branch, &mut report)?;
+        }
+
+        Ok(report)
+    }
+
+    fn is_protected(&self, branch: &str) -> Result<bool> {
+        for pattern in &self.protected {
+            let glob = Glob::new(pattern)
+                .map_err(|e| anyhow!("Invalid protected branch pattern {}: {}", pattern, e))?;
+            if glob.compile_matcher().is_match(branch) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// allow/deny 규칙 검사
+    fn check_name(&self, branch: &str, report: &mut Report) -> Result<()> {
+        for pattern in &self.deny {
+            if compile(pattern)?.is_match(branch) {
+                report.error(format!(
+                    "{} branch name \"{}\" matches the denied pattern {}",
+                    "✖".red(),
+                    branch,
+                    pattern
+                ));
+                return Ok(());
+            }
+        }
+
+        if self.allow.is_empty() {
+            return Ok(());
+        }
+        for pattern in &self.allow {
+            if compile(pattern)?.is_match(branch) {
+                return Ok(());
+            }
+        }
+
+        report.error(format!(
+            "{} branch name \"{}\" does not match any allowed pattern: {}",
+            "✖".red(),
+            branch,
+            self.allow.join(", ")
+        ));
+        Ok(())
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| anyhow!("Invalid branch name pattern {}: {}", pattern, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    const ZERO_SHA: &str = "0000000000000000000000000000000000000000";
+    const SHA: &str = "1111111111111111111111111111111111111111";
+
+    fn rules() -> BranchNameRules {
+        BranchNameRules {
+            allow: vec![r"^(feat|fix)/[A-Z]+-\d+-".to_string()],
+            deny: vec!["^tmp/".to_string()],
+            ..BranchNameRules::default()
+        }
+    }
+
+    fn pre_push(lines: &[String]) -> HookContext {
+        HookContext::new("pre-push", &["origin".to_string(), "url".to_string()])
+            .with_stdin(lines.concat())
+    }
+
+    fn push_line(remote_ref: &str, local_sha: &str) -> String {
+        format!("refs/heads/local {} {} {}\n", local_sha, remote_ref, SHA)
+    }
+
+    #[test]
+    fn test_check_name() {
+        let rules = rules();
+        let check = |branch: &str| {
+            let mut report = Report::default();
+            rules.check_name(branch, &mut report).unwrap();
+            report
+        };
+
+        assert!(check("feat/ABC-123-add-doctor").is_success());
+        assert!(check("fix/OPS-7-typo").is_success());
+        assert!(!check("feature/ABC-123-x").is_success());
+        assert!(!check("feat/abc-123-x").is_success());
+        assert!(check("tmp/feat/ABC-1-x").render().contains("denied pattern ^tmp/"));
+        assert!(check("wip").render().contains("does not match any allowed pattern"));
+
+        let invalid = BranchNameRules {
+            allow: vec!["(".to_string()],
+            ..BranchNameRules::default()
+        };
+        assert!(invalid.check_name("x", &mut Report::default()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pre_push_checks_pushed_branches() {
+        let rules = rules();
+
+        let context = pre_push(&[push_line("refs/heads/feat/ABC-1-x", SHA)]);
+        assert!(rules.check(&context).await.unwrap().is_success());
+
+        let context = pre_push(&[
+            push_line("refs/heads/feat/ABC-1-x", SHA),
+            push_line("refs/heads/bad-name", SHA),
+        ]);
+        let report = rules.check(&context).await.unwrap();
+        assert!(!report.is_success());
+        assert!(report.render().contains("\"bad-name\""));
+
+        let context = pre_push(&[push_line("refs/heads/main", SHA)]);
+        let report = rules.check(&context).await.unwrap();
+        assert!(report.render().contains("pushing directly to protected branch \"main\""));
+
+        let context = pre_push(&[push_line("refs/heads/main", ZERO_SHA)]);
+        let report = rules.check(&context).await.unwrap();
+        assert!(report.render().contains("deleting protected branch \"main\""));
+
+        // 잘못된 이름의 브랜치 삭제와 태그 push는 허용
+        let context = pre_push(&[
+            push_line("refs/heads/bad-name", ZERO_SHA),
+            push_line("refs/tags/v1.0.0", SHA),
+        ]);
+        assert!(rules.check(&context).await.unwrap().is_success());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_pre_commit_checks_current_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        let git = |args: &'static [&'static str]| async move {
+            tokio::process::Command::new("git").args(args).output().await.unwrap();
+        };
+        git(&["init", "-q", "-b", "main"]).await;
+
+        let rules = BranchNameRules {
+            protected: vec!["main".to_string(), "release/*".to_string()],
+            ..rules()
+        };
+        let context = HookContext::new("pre-commit", &[]);
+
+        let report = rules.check(&context).await.unwrap();
+        assert!(report.render().contains("committing directly to protected branch \"main\""));
+
+        git(&["checkout", "-q", "-b", "release/1.0"]).await;
+        assert!(!rules.check(&context).await.unwrap().is_success());
+
+        git(&["checkout", "-q", "-b", "feat/ABC-1-x"]).await;
+        assert!(rules.check(&context).await.unwrap().is_success());
+
+        git(&["checkout", "-q", "-b", "my-branch"]).await;
+        assert!(!rules.check(&context).await.unwrap().is_success());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -0,0 +1,520 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use super::{comment_char, Report};
+use crate::context::HookContext;
+
+/// `git commit -v`가 메시지 아래에 붙이는 diff의 시작 표시
+const SCISSORS: &str = "------------------------ >8 ------------------------";
+
+/// 검사하지 않는 git 자동 생성 메시지의 시작 부분
+const IGNORED_PREFIXES: &[&str] = &["Merge ", "Merged ", "Revert \"", "fixup! ", "squash! ", "amend! "];
+
+/// Conventional Commits 검사 규칙
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct ConventionalCommitsRules {
+    /// 허용하는 type
+    pub types: Vec<String>,
+    /// 허용하는 scope (비어 있으면 모두 허용)
+    pub scopes: Vec<String>,
+    /// scope 없는 메시지 거부
+    pub require_scope: bool,
+    /// `!`와 `BREAKING CHANGE:` footer 허용
+    pub allow_breaking: bool,
+    /// 첫 줄의 최대 길이 (null이면 검사하지 않음)
+    pub subject_max_length: Option<usize>,
+    /// 본문 각 줄의 최대 길이 (null이면 검사하지 않음)
+    pub body_max_line_length: Option<usize>,
+    /// 첫 줄이 일치하면 검사하지 않는 정규식
+    pub ignore: Vec<String>,
+}
+
+impl Default for ConventionalCommitsRules {
+    fn default() -> Self {
+        Self {
+            types: [
+                "build", "chore", "ci", "docs", "feat", "fix", "perf", "refactor", "revert",
+                "style", "test",
+            ]
+            .iter()
+            .map(|kind| kind.to_string())
+            .collect(),
+            scopes: Vec::new(),
+            require_scope: false,
+            allow_breaking: true,
+            subject_max_length: Some(72),
+            body_max_line_length: Some(100),
+            ignore: Vec::new(),
+        }
+    }
+}
+
+/// 첫 줄에서 문제가 있는 부분 (바이트 위치)
+#[derive(Debug, PartialEq)]
+struct Issue {
+    start: usize,
+    end: usize,
+    message: String,
+}
+
+impl Issue {
+    fn new(start: usize, end: usize, message: impl Into<String>) -> Self {
+        Self {
+            start,
+            end: end.max(start + 1),
+            message: message.into(),
+        }
+    }
+}
+
+/// `type(scope)!: description` 형식으로 나눈 첫 줄
+struct Header<'a> {
+    kind: &'a str,
+    /// scope와 그 시작 위치
+    scope: Option<(&'a str, usize)>,
+    /// `!`의 위치
+    breaking: Option<usize>,
+}
+
+impl ConventionalCommitsRules {
+    /// `commit-msg`의 메시지 파일 검사
+    pub async fn check(&self, context: &HookContext) -> Result<Report> {
+        let message_file = context.message_file().ok_or_else(|| {
+            anyhow!(
+                "conventional-commits needs a commit message file; use it in the commit-msg hook (not {})",
+                context.hook_name
+            )
+        })?;
+        let message = fs::read_to_string(message_file).await?;
+
+        self.check_message(&message, comment_char().await)
+    }
+
+    /// 메시지 내용 검사
+    fn check_message(&self, message: &str, comment_char: char) -> Result<Report> {
+        let mut report = Report::default();
+        let lines = clean_message(message, comment_char);
+
+        let Some(header) = lines.first() else {
+            report.error("✖ commit message is empty".red().to_string());
+            return Ok(report);
+        };
+
+        if self.is_ignored(header)? {
+            report.info(format!("{}", "○ skipped (merge, fixup or revert message)".dimmed()));
+            return Ok(report);
+        }
+
+        let mut issues = Vec::new();
+        let mut errors = Vec::new();
+
+        match parse_header(header) {
+            Ok(parsed) => issues.extend(self.check_header(header, &parsed)),
+            Err(issue) => issues.push(issue),
+        }
+
+        if let Some(max) = self.subject_max_length {
+            let length = header.chars().count();
+            if length > max {
+                let start = header.char_indices().nth(max).map_or(header.len(), |(index, _)| index);
+                issues.push(Issue::new(
+                    start,
+                    header.len(),
+                    format!("subject is {} characters long (max {})", length, max),
+                ));
+            }
+        }
+
+        if lines.get(1).is_some_and(|line| !line.trim().is_empty()) {
+            errors.push("line 2 must be blank to separate the subject from the body".to_string());
+        }
+
+        if let Some(max) = self.body_max_line_length {
+            for (index, line) in lines.iter().enumerate().skip(2) {
+                let length = line.chars().count();
+                // URL은 줄을 나눌 수 없으므로 제외
+                if length > max && !line.contains("://") {
+                    errors.push(format!(
+                        "line {} is {} characters long (max {})",
+                        index + 1,
+                        length,
+                        max
+                    ));
+                }
+            }
+        }
+
+        errors.extend(self.check_footers(&lines));
+
+        if issues.is_empty() && errors.is_empty() {
+            return Ok(report);
+        }
+
+        report.error(format!(
+            "{}",
+            "✖ commit message does not follow Conventional Commits".red()
+        ));
+        if !issues.is_empty() {
+            report.error(format!("  {}", header));
+            for issue in &issues {
+                let offset = header[..issue.start].chars().count();
+                let width = header[issue.start..issue.end.min(header.len())]
+                    .chars()
+                    .count()
+                    .max(1);
+                report.error(format!(
+                    "  {}{} {}",
+                    " ".repeat(offset),
+                    "^".repeat(width).red(),
+                    issue.message
+                ));
+            }
+        }
+        for error in errors {
+            report.error(format!("  {}", error));
+        }
+        report.error(format!(
+            "{}",
+            "  expected: <type>(<scope>)!: <description>".dimmed()
+        ));
+
+        Ok(report)
+    }
+
+    fn is_ignored(&self, header: &str) -> Result<bool> {
+        if IGNORED_PREFIXES.iter().any(|prefix| header.starts_with(prefix)) {
+            return Ok(true);
+        }
+
+        for pattern in &self.ignore {
+            let regex = Regex::new(pattern)
+                .map_err(|e| anyhow!("Invalid ignore pattern {}: {}", pattern, e))?;
+            if regex.is_match(header) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn check_header(&self, header: &str, parsed: &Header) -> Vec<Issue> {
+        let mut issues = Vec::new();
+
+        if !self.types.iter().any(|kind| kind == parsed.kind) {
+            issues.push(Issue::new(
+                0,
+                parsed.kind.len(),
+                format!(
+                    "type \"{}\" is not allowed (allowed: {})",
+                    parsed.kind,
+                    self.types.join(", ")
+                ),
+            ));
+        }
+
+        match parsed.scope {
+            Some((scope, start)) if !self.scopes.is_empty() => {
+                // `feat(api,cli)`처럼 여러 scope를 쓸 수 있음
+                let unknown: Vec<&str> = scope
+                    .split([',', '/'])
+                    .map(str::trim)
+                    .filter(|part| !self.scopes.iter().any(|allowed| allowed == part))
+                    .collect();
+                if !unknown.is_empty() {
+                    issues.push(Issue::new(
+                        start,
+                        start + scope.len(),
+                        format!(
+                            "scope \"{}\" is not allowed (allowed: {})",
+                            unknown.join(", "),
+                            self.scopes.join(", ")
+                        ),
+                    ));
+                }
+            }
+            None if self.require_scope => {
+                let end = parsed.kind.len();
+                issues.push(Issue::new(end, end + 1, "scope is required, e.g. feat(api): ..."));
+            }
+            _ => {}
+        }
+
+        if let Some(position) = parsed.breaking {
+            if !self.allow_breaking {
+                issues.push(Issue::new(position, position + 1, "breaking changes are not allowed"));
+            }
+        }
+
+        if header.ends_with(char::is_whitespace) {
+            let trimmed = header.trim_end().len();
+            issues.push(Issue::new(trimmed, header.len(), "trailing whitespace"));
+        }
+
+        issues
+    }
+
+    /// 마지막 문단의 footer (`Refs: #1`, `BREAKING CHANGE: ...`) 검사
+    fn check_footers(&self, lines: &[String]) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        let Some(start) = lines.iter().rposition(|line| line.trim().is_empty()) else {
+            return errors;
+        };
+
+        for (index, line) in lines.iter().enumerate().skip(start + 1) {
+            let Some(token_end) = breaking_token_length(line) else {
+                continue;
+            };
+
+            let token = &line[..token_end];
+            if token != "BREAKING CHANGE" && token != "BREAKING-CHANGE" {
+                errors.push(format!(
+                    "line {}: write \"{}\" as \"BREAKING CHANGE\"",
+                    index + 1,
+                    token
+                ));
+            }
+            if line[token_end..].trim_start_matches(':').trim().is_empty() {
+                errors.push(format!(
+                    "line {}: BREAKING CHANGE needs a description",
+                    index + 1
+                ));
+            }
+            if !self.allow_breaking {
+                errors.push(format!("line {}: breaking changes are not allowed", index + 1));
+            }
+        }
+
+        errors
+    }
+}
+
+/// `BREAKING CHANGE:` footer라면 토큰 길이 반환 (대소문자 무시)
+fn breaking_token_length(line: &str) -> Option<usize> {
+    let token = line.get(..15)?;
+    let is_breaking = token.eq_ignore_ascii_case("breaking change")
+        || token.eq_ignore_ascii_case("breaking-change");
+    (is_breaking && line[15..].starts_with(':')).then_some(15)
+}
+
+/// 주석과 `git commit -v`의 diff를 제외한 메시지 줄
+fn clean_message(message: &str, comment_char: char) -> Vec<String> {
+    let mut lines: Vec<String> = message
+        .lines()
+        .take_while(|line| !(line.starts_with(comment_char) && line.ends_with(SCISSORS)))
+        .filter(|line| !line.starts_with(comment_char))
+        .map(String::from)
+        .collect();
+
+    while lines.first().is_some_and(|line| line.trim().is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    lines
+}
+
+fn parse_header(header: &str) -> std::result::Result<Header<'_>, Issue> {
+    let type_end = header.find(['(', '!', ':']).unwrap_or(header.len());
+    let kind = &header[..type_end];
+
+    if kind.is_empty() {
+        return Err(Issue::new(0, 1, "missing type, e.g. feat: ..."));
+    }
+    if !kind.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(Issue::new(0, type_end, "missing '<type>: ' prefix"));
+    }
+
+    let mut position = type_end;
+    let mut scope = None;
+    if header[position..].starts_with('(') {
+        let Some(close) = header[position..].find(')') else {
+            return Err(Issue::new(position, header.len(), "scope is missing a closing ')'"));
+        };
+        let value = &header[position + 1..position + close];
+        if value.trim().is_empty() {
+            return Err(Issue::new(position, position + close + 1, "scope is empty"));
+        }
+        scope = Some((value, position + 1));
+        position += close + 1;
+    }
+
+    let mut breaking = None;
+    if header[position..].starts_with('!') {
+        breaking = Some(position);
+        position += 1;
+    }
+
+    if !header[position..].starts_with(": ") {
+        return Err(Issue::new(position, position + 1, "expected ': ' after the type"));
+    }
+    if header[position + 2..].trim().is_empty() {
+        return Err(Issue::new(position + 2, position + 3, "description is empty"));
+    }
+
+    Ok(Header {
+        kind,
+        scope,
+        breaking,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(rules: &ConventionalCommitsRules, message: &str) -> Report {
+        rules.check_message(message, '#').unwrap()
+    }
+
+    fn assert_fails_with(rules: &ConventionalCommitsRules, message: &str, expected: &str) {
+        let report = check(rules, message);
+        assert!(!report.is_success(), "{:?} should fail", message);
+        assert!(
+            report.render().contains(expected),
+            "{:?} should mention {:?}, got:\n{}",
+            message,
+            expected,
+            report.render()
+        );
+    }
+
+    #[test]
+    fn test_valid_messages() {
+        let rules = ConventionalCommitsRules::default();
+        for message in [
+            "feat: add doctor command",
+            "fix(runner): forward stdin\n",
+            "feat(api)!: drop v1 endpoints",
+            "refactor!: rename config keys\n\nBody text explaining why.\n\nBREAKING CHANGE: config keys were renamed\nRefs: #12",
+            "docs: typo\n# Please enter the commit message\n# Lines starting with '#' will be ignored",
+            "chore: bump\n\n# ------------------------ >8 ------------------------\ndiff --git a/x b/x\n",
+        ] {
+            let report = check(&rules, message);
+            assert!(report.is_success(), "{:?}:\n{}", message, report.render());
+        }
+    }
+
+    #[test]
+    fn test_invalid_headers() {
+        let rules = ConventionalCommitsRules::default();
+        assert_fails_with(&rules, "add doctor command", "missing '<type>: ' prefix");
+        assert_fails_with(&rules, ": nothing", "missing type");
+        assert_fails_with(&rules, "feature: add", "type \"feature\" is not allowed");
+        assert_fails_with(&rules, "feat(api: add", "closing ')'");
+        assert_fails_with(&rules, "feat(): add", "scope is empty");
+        assert_fails_with(&rules, "feat:add", "expected ': ' after the type");
+        assert_fails_with(&rules, "feat: ", "description is empty");
+        assert_fails_with(&rules, "", "commit message is empty");
+    }
+
+    #[test]
+    fn test_error_points_at_offending_part() {
+        let rules = ConventionalCommitsRules {
+            scopes: vec!["api".to_string(), "cli".to_string()],
+            ..ConventionalCommitsRules::default()
+        };
+
+        let report = check(&rules, "feat(web): add page");
+        assert!(!report.is_success());
+        let render = report.render();
+        let lines: Vec<&str> = render.lines().collect();
+        assert_eq!(lines[1], "  feat(web): add page");
+        // 캐럿이 scope 아래에 표시됨
+        assert!(lines[2].starts_with("       "));
+        assert!(lines[2].contains("^^^"));
+        assert!(lines[2].contains("scope \"web\" is not allowed (allowed: api, cli)"));
+
+        assert!(check(&rules, "feat(api,cli): add").is_success());
+        assert!(check(&rules, "feat: no scope").is_success());
+    }
+
+    #[test]
+    fn test_scope_and_breaking_rules() {
+        let rules = ConventionalCommitsRules {
+            require_scope: true,
+            allow_breaking: false,
+            ..ConventionalCommitsRules::default()
+        };
+        assert_fails_with(&rules, "feat: add", "scope is required");
+        assert_fails_with(&rules, "feat(api)!: add", "breaking changes are not allowed");
+        assert_fails_with(
+            &rules,
+            "feat(api): add\n\nBREAKING CHANGE: removed x",
+            "line 3: breaking changes are not allowed",
+        );
+    }
+
+    #[test]
+    fn test_footers() {
+        let rules = ConventionalCommitsRules::default();
+        assert_fails_with(
+            &rules,
+            "feat: add\n\nbreaking change: removed x",
+            "write \"breaking change\" as \"BREAKING CHANGE\"",
+        );
+        assert_fails_with(&rules, "feat: add\n\nBREAKING CHANGE:", "needs a description");
+        assert!(check(&rules, "feat: add\n\nBREAKING-CHANGE: removed x").is_success());
+    }
+
+    #[test]
+    fn test_length_limits() {
+        let rules = ConventionalCommitsRules::default();
+        let long_subject = format!("feat: {}", "a".repeat(80));
+        assert_fails_with(&rules, &long_subject, "subject is 86 characters long (max 72)");
+
+        let long_body = format!("feat: add\n\n{}", "word ".repeat(30));
+        assert_fails_with(&rules, &long_body, "line 3 is 150 characters long (max 100)");
+        assert_fails_with(&rules, "feat: add\nbody", "line 2 must be blank");
+
+        // URL은 길어도 허용
+        let url = format!("feat: add\n\nSee https://example.com/{}", "a".repeat(120));
+        assert!(check(&rules, &url).is_success());
+
+        let unlimited = ConventionalCommitsRules {
+            subject_max_length: None,
+            body_max_line_length: None,
+            ..ConventionalCommitsRules::default()
+        };
+        assert!(check(&unlimited, &long_subject).is_success());
+        assert!(check(&unlimited, &long_body).is_success());
+    }
+
+    #[test]
+    fn test_skips_generated_messages() {
+        let rules = ConventionalCommitsRules {
+            ignore: vec!["^WIP".to_string()],
+            ..ConventionalCommitsRules::default()
+        };
+        for message in [
+            "Merge branch 'main' into feat/x",
+            "Revert \"feat: add doctor\"\n\nThis reverts commit abc.",
+            "fixup! feat: add doctor",
+            "squash! fix: typo",
+            "WIP on something",
+        ] {
+            assert!(check(&rules, message).is_success(), "{:?}", message);
+        }
+
+        let invalid = ConventionalCommitsRules {
+            ignore: vec!["(".to_string()],
+            ..ConventionalCommitsRules::default()
+        };
+        assert!(invalid.check_message("feat: add", '#').is_err());
+    }
+
+    #[test]
+    fn test_custom_comment_char() {
+        let rules = ConventionalCommitsRules::default();
+        let report = rules
+            .check_message("fix: typo\n; comment line that is not a valid footer", ';')
+            .unwrap();
+        assert!(report.is_success(), "{}", report.render());
+    }
+}
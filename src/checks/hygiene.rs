@@ -0,0 +1,576 @@
+use anyhow::Result;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tokio::fs;
+
+use super::{added_lines, Report};
+use crate::git::{Git, StagedBlob};
+use crate::staged::FileMatcher;
+
+/// 한 파일에서 보여 줄 최대 줄 번호 수
+const MAX_LINE_NUMBERS: usize = 10;
+
+/// git이 binary 여부를 판단할 때 보는 앞부분 크기
+const BINARY_SNIFF_LENGTH: usize = 8000;
+
+/// staged 파일의 conflict marker, 공백, 줄바꿈, 파일 이름 검사 설정
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct HygieneRules {
+    /// 남아 있는 merge conflict marker (`<<<<<<<`, `=======`, `>>>>>>>`)
+    pub conflict_markers: bool,
+    /// 줄 끝의 공백과 탭
+    pub trailing_whitespace: bool,
+    /// 마지막 줄의 개행 누락
+    pub final_newline: bool,
+    /// 한 파일 안에 CRLF와 LF가 섞인 줄바꿈
+    pub mixed_line_endings: bool,
+    /// 대소문자만 다른 파일 이름 (대소문자를 구분하지 않는 파일 시스템에서 충돌)
+    pub case_conflicts: bool,
+    /// 공백, 마지막 개행, 줄바꿈 문제를 고치고 다시 stage
+    pub fix: bool,
+    /// 검사하지 않을 파일 glob (`glob` 단계와 같은 규칙)
+    pub exclude: Vec<String>,
+}
+
+impl Default for HygieneRules {
+    fn default() -> Self {
+        Self {
+            conflict_markers: true,
+            trailing_whitespace: true,
+            final_newline: true,
+            mixed_line_endings: true,
+            case_conflicts: true,
+            fix: false,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// 한 파일에서 발견된 문제
+#[derive(Debug, Default, PartialEq)]
+struct Findings {
+    conflict_markers: Vec<usize>,
+    trailing_whitespace: Vec<usize>,
+    missing_final_newline: bool,
+    mixed_line_endings: bool,
+}
+
+impl Findings {
+    /// `fix`로 고칠 수 있는 문제가 있는지 확인
+    fn is_fixable(&self) -> bool {
+        !self.trailing_whitespace.is_empty()
+            || self.missing_final_newline
+            || self.mixed_line_endings
+    }
+}
+
+impl HygieneRules {
+    /// index에 있는 staged 파일 내용 검사 (`fix`면 고친 내용을 다시 stage)
+    pub async fn check(&self, files: Option<&[String]>) -> Result<Report> {
+        let mut report = Report::default();
+        let files = match files {
+            Some(files) => files.to_vec(),
+            None => Git::get_staged_files().await?,
+        };
+        let exclude = FileMatcher::new(&self.exclude)?;
+        let files: Vec<String> = files
+            .into_iter()
+            .filter(|file| !exclude.matches(file))
+            .collect();
+        if files.is_empty() {
+            return Ok(report);
+        }
+
+        let mut problems = Vec::new();
+        let mut fixed = Vec::new();
+        let mut fixable = false;
+
+        for blob in Git::get_staged_blobs(&files).await? {
+            if is_binary(&blob.content) {
+                continue;
+            }
+
+            let findings = self.inspect(&blob.content);
+            if self.fix && findings.is_fixable() {
+                self.fix_blob(&blob).await?;
+                fixed.push(blob.path.clone());
+
+                // conflict marker는 고칠 수 없으므로 그대로 보고
+                let remaining = Findings {
+                    conflict_markers: findings.conflict_markers,
+                    ..Findings::default()
+                };
+                describe(&blob.path, &remaining, &mut problems);
+            } else {
+                fixable |= findings.is_fixable();
+                describe(&blob.path, &findings, &mut problems);
+            }
+        }
+
+        if self.case_conflicts {
+            let index_files = Git::get_index_files().await?;
+            for group in case_conflicts(&index_files, &files) {
+                problems.push(format!(
+                    "  {} differ only in case",
+                    group
+                        .iter()
+                        .map(|path| path.bold().to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+
+        for file in &fixed {
+            report.info(format!("🔧 fixed whitespace and line endings in {}", file));
+        }
+        if problems.is_empty() {
+            return Ok(report);
+        }
+
+        report.error(format!("{}", "✖ problems in staged files:".red()));
+        for problem in problems {
+            report.error(problem);
+        }
+        if fixable {
+            report.error(format!(
+                "{}",
+                "  set \"fix\": true to fix whitespace and line endings automatically".dimmed()
+            ));
+        }
+
+        Ok(report)
+    }
+
+    /// 켜져 있는 검사로 파일 내용 검사
+    fn inspect(&self, content: &[u8]) -> Findings {
+        let mut findings = Findings::default();
+        let mut markers = Vec::new();
+        let mut has_start_or_end = false;
+
+        for (index, line) in content.split_inclusive(|byte| *byte == b'\n').enumerate() {
+            let number = index + 1;
+            let text = strip_line_ending(line);
+
+            if self.conflict_markers {
+                if text.starts_with(b"<<<<<<< ") || text.starts_with(b">>>>>>> ") {
+                    has_start_or_end = true;
+                    markers.push(number);
+                } else if text == b"=======" || text.starts_with(b"||||||| ") {
+                    markers.push(number);
+                }
+            }
+            if self.trailing_whitespace && (text.ends_with(b" ") || text.ends_with(b"\t")) {
+                findings.trailing_whitespace.push(number);
+            }
+        }
+
+        // `=======`만 있는 줄은 문서의 제목 밑줄일 수 있으므로 다른 marker가 있을 때만 보고
+        if has_start_or_end {
+            findings.conflict_markers = markers;
+        }
+
+        findings.missing_final_newline =
+            self.final_newline && !content.is_empty() && !content.ends_with(b"\n");
+
+        let (crlf, lf) = count_line_endings(content);
+        findings.mixed_line_endings = self.mixed_line_endings && crlf > 0 && lf > 0;
+
+        findings
+    }
+
+    /// 켜져 있는 검사 중 고칠 수 있는 문제를 고친 내용
+    fn fix_content(&self, content: &[u8]) -> Vec<u8> {
+        self.fix_lines(content, content, &BTreeSet::new())
+    }
+
+    /// `staged` 내용을 기준으로 `content`를 고치되 `unstaged` 줄 (1부터)은 그대로 두기
+    fn fix_lines(&self, content: &[u8], staged: &[u8], unstaged: &BTreeSet<usize>) -> Vec<u8> {
+        let (crlf, lf) = count_line_endings(staged);
+        // 더 많이 쓰인 줄바꿈으로 통일 (같으면 LF)
+        let ending: &[u8] = if crlf > lf { b"\r\n" } else { b"\n" };
+
+        let mut fixed = Vec::with_capacity(content.len() + ending.len());
+        let mut last_unstaged = false;
+        for (index, line) in content.split_inclusive(|byte| *byte == b'\n').enumerate() {
+            last_unstaged = unstaged.contains(&(index + 1));
+            if last_unstaged {
+                fixed.extend_from_slice(line);
+                continue;
+            }
+
+            let mut text = strip_line_ending(line);
+            if self.trailing_whitespace {
+                while let [rest @ .., b' ' | b'\t'] = text {
+                    text = rest;
+                }
+            }
+            fixed.extend_from_slice(text);
+
+            if self.mixed_line_endings && crlf > 0 && lf > 0 && line.ends_with(b"\n") {
+                fixed.extend_from_slice(ending);
+            } else {
+                fixed.extend_from_slice(&line[strip_line_ending(line).len()..]);
+            }
+        }
+
+        if self.final_newline && !last_unstaged && !fixed.is_empty() && !fixed.ends_with(b"\n") {
+            fixed.extend_from_slice(ending);
+        }
+
+        fixed
+    }
+
+    /// index와 작업 트리의 파일을 각각 고치기 (stage되지 않은 변경은 그대로 둠)
+    async fn fix_blob(&self, blob: &StagedBlob) -> Result<()> {
+        // index를 바꾸기 전에 작업 트리에서 stage되지 않은 줄을 찾아 둠
+        let unstaged: BTreeSet<usize> = added_lines(&Git::get_unstaged_diff(&blob.path).await?)
+            .into_iter()
+            .map(|added| added.line)
+            .collect();
+
+        Git::stage_blob(&blob.path, &blob.mode, &self.fix_content(&blob.content)).await?;
+
+        let path = Path::new(&blob.path);
+        if path.is_file() {
+            let content = fs::read(path).await?;
+            if !is_binary(&content) {
+                let fixed = self.fix_lines(&content, &blob.content, &unstaged);
+                if fixed != content {
+                    fs::write(path, fixed).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// 파일의 문제를 출력할 줄로 만들기
+fn describe(path: &str, findings: &Findings, problems: &mut Vec<String>) {
+    let path = path.bold();
+    if !findings.conflict_markers.is_empty() {
+        problems.push(format!(
+            "  {}: conflict markers on {}",
+            path,
+            line_numbers(&findings.conflict_markers)
+        ));
+    }
+    if !findings.trailing_whitespace.is_empty() {
+        problems.push(format!(
+            "  {}: trailing whitespace on {}",
+            path,
+            line_numbers(&findings.trailing_whitespace)
+        ));
+    }
+    if findings.missing_final_newline {
+        problems.push(format!("  {}: no newline at end of file", path));
+    }
+    if findings.mixed_line_endings {
+        problems.push(format!("  {}: mixed line endings (CRLF and LF)", path));
+    }
+}
+
+/// `line 3` 또는 `lines 3, 7, 12, …`
+fn line_numbers(lines: &[usize]) -> String {
+    let mut numbers: Vec<String> = lines
+        .iter()
+        .take(MAX_LINE_NUMBERS)
+        .map(|line| line.to_string())
+        .collect();
+    if lines.len() > MAX_LINE_NUMBERS {
+        numbers.push("…".to_string());
+    }
+
+    let label = if lines.len() == 1 { "line" } else { "lines" };
+    format!("{} {}", label, numbers.join(", "))
+}
+
+/// 줄 끝의 `\n` 또는 `\r\n`을 뺀 내용
+fn strip_line_ending(line: &[u8]) -> &[u8] {
+    match line.strip_suffix(b"\n") {
+        Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+        None => line,
+    }
+}
+
+/// (CRLF 수, CR 없는 LF 수)
+fn count_line_endings(content: &[u8]) -> (usize, usize) {
+    let mut crlf = 0;
+    let mut lf = 0;
+    for (index, byte) in content.iter().enumerate() {
+        if *byte == b'\n' {
+            if index > 0 && content[index - 1] == b'\r' {
+                crlf += 1;
+            } else {
+                lf += 1;
+            }
+        }
+    }
+
+    (crlf, lf)
+}
+
+/// git과 같은 방식으로 binary 파일 판단 (앞부분에 NUL이 있으면 binary)
+fn is_binary(content: &[u8]) -> bool {
+    content
+        .iter()
+        .take(BINARY_SNIFF_LENGTH)
+        .any(|byte| *byte == 0)
+}
+
+/// 대소문자만 다른 경로 묶음 중 `staged` 파일이 포함된 것
+///
+/// `Docs/a.md`와 `docs/b.md`처럼 디렉토리 이름만 다른 경우도 포함합니다.
+fn case_conflicts(index_files: &[String], staged: &[String]) -> Vec<BTreeSet<String>> {
+    let mut spellings: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for file in index_files {
+        for prefix in path_prefixes(file) {
+            spellings
+                .entry(prefix.to_lowercase())
+                .or_default()
+                .insert(prefix.to_string());
+        }
+    }
+
+    let mut conflicts = BTreeMap::new();
+    for file in staged {
+        for prefix in path_prefixes(file) {
+            let key = prefix.to_lowercase();
+            if let Some(group) = spellings.get(&key).filter(|group| group.len() > 1) {
+                conflicts.insert(key, group.clone());
+                // 상위 디렉토리가 충돌하면 그 아래 경로는 따로 보고하지 않음
+                break;
+            }
+        }
+    }
+
+    conflicts.into_values().collect()
+}
+
+/// `a/b/c.txt` → `a`, `a/b`, `a/b/c.txt`
+fn path_prefixes(path: &str) -> impl Iterator<Item = &str> {
+    path.match_indices('/')
+        .map(move |(index, _)| &path[..index])
+        .chain(std::iter::once(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|path| path.to_string()).collect()
+    }
+
+    #[test]
+    fn test_inspect() {
+        let rules = HygieneRules::default();
+
+        let findings = rules.inspect(b"a\n<<<<<<< HEAD\nb \n=======\nc\t\n>>>>>>> feat\n");
+        assert_eq!(findings.conflict_markers, vec![2, 4, 6]);
+        assert_eq!(findings.trailing_whitespace, vec![3, 5]);
+        assert!(!findings.missing_final_newline);
+        assert!(!findings.mixed_line_endings);
+
+        let findings = rules.inspect(b"a\r\nb\nc");
+        assert!(findings.missing_final_newline);
+        assert!(findings.mixed_line_endings);
+        assert!(findings.trailing_whitespace.is_empty());
+
+        // 제목 밑줄과 CRLF만 쓰는 파일, 빈 파일은 통과
+        assert_eq!(rules.inspect(b"Title\n=======\n"), Findings::default());
+        assert_eq!(rules.inspect(b"a\r\nb\r\n"), Findings::default());
+        assert_eq!(rules.inspect(b""), Findings::default());
+    }
+
+    #[test]
+    fn test_inspect_respects_toggles() {
+        let rules = HygieneRules {
+            conflict_markers: false,
+            trailing_whitespace: false,
+            final_newline: false,
+            mixed_line_endings: false,
+            ..HygieneRules::default()
+        };
+        assert_eq!(
+            rules.inspect(b"<<<<<<< HEAD\na \r\n>>>>>>> x"),
+            Findings::default()
+        );
+    }
+
+    #[test]
+    fn test_fix_content() {
+        let rules = HygieneRules::default();
+        assert_eq!(rules.fix_content(b"a  \nb\t\nc"), b"a\nb\nc\n");
+        assert_eq!(rules.fix_content(b"a\r\nb\r\nc\n"), b"a\r\nb\r\nc\r\n");
+        assert_eq!(rules.fix_content(b"a\r\nb\nc\n"), b"a\nb\nc\n");
+        assert_eq!(rules.fix_content(b"a\r\nb \r\nc"), b"a\r\nb\r\nc\r\n");
+        assert_eq!(rules.fix_content(b""), b"");
+
+        // 꺼진 검사의 문제는 고치지 않음
+        let whitespace_only = HygieneRules {
+            final_newline: false,
+            mixed_line_endings: false,
+            ..HygieneRules::default()
+        };
+        assert_eq!(whitespace_only.fix_content(b"a \r\nb \nc "), b"a\r\nb\nc");
+    }
+
+    #[test]
+    fn test_case_conflicts() {
+        let index = files(&[
+            "README.md",
+            "readme.md",
+            "Docs/a.md",
+            "docs/b.md",
+            "src/main.rs",
+        ]);
+
+        let conflicts = case_conflicts(&index, &files(&["readme.md", "docs/b.md"]));
+        assert_eq!(
+            conflicts,
+            vec![
+                BTreeSet::from(["Docs".to_string(), "docs".to_string()]),
+                BTreeSet::from(["README.md".to_string(), "readme.md".to_string()]),
+            ]
+        );
+
+        assert!(case_conflicts(&index, &files(&["src/main.rs"])).is_empty());
+    }
+
+    #[test]
+    fn test_line_numbers() {
+        assert_eq!(line_numbers(&[3]), "line 3");
+        assert_eq!(line_numbers(&[3, 7]), "lines 3, 7");
+        let many: Vec<usize> = (1..=12).collect();
+        assert_eq!(
+            line_numbers(&many),
+            "lines 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, …"
+        );
+    }
+
+    async fn git(args: &[&str]) {
+        tokio::process::Command::new("git")
+            .args(args)
+            .output()
+            .await
+            .unwrap();
+    }
+
+    async fn staged_content(file: &str) -> Vec<u8> {
+        Git::get_staged_blobs(&files(&[file]))
+            .await
+            .unwrap()
+            .remove(0)
+            .content
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_check_reports_staged_problems() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("merge.txt", "<<<<<<< HEAD\na\n=======\nb\n>>>>>>> x\n")
+            .await
+            .unwrap();
+        fs::write("space.txt", "a \nb").await.unwrap();
+        fs::write("clean.txt", "ok\n").await.unwrap();
+        fs::write("logo.png", [0x89, b'P', b'N', b'G', 0, b' ', b'\n'])
+            .await
+            .unwrap();
+        git(&["add", "."]).await;
+
+        let report = HygieneRules::default().check(None).await.unwrap();
+        assert!(!report.is_success());
+        let render = report.render();
+        assert!(render.contains("conflict markers on lines 1, 3, 5"));
+        assert!(render.contains("trailing whitespace on line 1"));
+        assert!(render.contains("no newline at end of file"));
+        assert!(render.contains("\"fix\": true"));
+        assert!(!render.contains("clean.txt"));
+        assert!(!render.contains("logo.png"));
+
+        let rules = HygieneRules {
+            exclude: files(&["merge.txt", "space.txt"]),
+            ..HygieneRules::default()
+        };
+        assert!(rules.check(None).await.unwrap().is_success());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_check_fixes_index_and_working_tree() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("a.txt", "one \ntwo").await.unwrap();
+        git(&["add", "a.txt"]).await;
+        // stage되지 않은 변경은 commit에 들어가지 않아야 함
+        fs::write("a.txt", "one \ntwo\nthree \n").await.unwrap();
+
+        let rules = HygieneRules {
+            fix: true,
+            ..HygieneRules::default()
+        };
+        let report = rules.check(None).await.unwrap();
+        assert!(report.is_success());
+        assert!(report
+            .render()
+            .contains("fixed whitespace and line endings in a.txt"));
+
+        assert_eq!(staged_content("a.txt").await, b"one\ntwo\n");
+        assert_eq!(
+            fs::read_to_string("a.txt").await.unwrap(),
+            "one\ntwo\nthree \n"
+        );
+
+        // conflict marker는 고칠 수 없으므로 실패
+        fs::write("b.txt", "<<<<<<< HEAD\n>>>>>>> x\n")
+            .await
+            .unwrap();
+        git(&["add", "b.txt"]).await;
+        assert!(!rules.check(None).await.unwrap().is_success());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_fix_keeps_unstaged_lines_in_working_tree() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("a.txt", "a \nb\nc \n").await.unwrap();
+        git(&["add", "a.txt"]).await;
+        // 같은 파일에 stage되지 않은 수정과 추가
+        fs::write("a.txt", "a \nB \nc \nd \n").await.unwrap();
+
+        let rules = HygieneRules {
+            fix: true,
+            ..HygieneRules::default()
+        };
+        assert!(rules.check(None).await.unwrap().is_success());
+
+        assert_eq!(staged_content("a.txt").await, b"a\nb\nc\n");
+        // staged 줄만 고치고 stage되지 않은 줄은 그대로
+        assert_eq!(fs::read_to_string("a.txt").await.unwrap(), "a\nB \nc\nd \n");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
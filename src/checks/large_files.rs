@@ -0,0 +1,217 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use super::Report;
+use crate::git::Git;
+use crate::staged::FileMatcher;
+
+/// staged 파일 크기와 binary 파일 검사 설정
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct LargeFilesRules {
+    /// 허용하는 최대 크기 (바이트 수 또는 `"500KB"`, `"5MB"` 같은 문자열)
+    #[serde(deserialize_with = "deserialize_size")]
+    pub max_size: u64,
+    /// binary 파일 거부
+    pub reject_binary: bool,
+    /// `reject_binary`여도 허용할 binary 파일 glob
+    pub allow_binary: Vec<String>,
+}
+
+impl Default for LargeFilesRules {
+    fn default() -> Self {
+        Self {
+            max_size: 500 * 1024,
+            reject_binary: false,
+            allow_binary: Vec::new(),
+        }
+    }
+}
+
+impl LargeFilesRules {
+    /// index에 기록된 blob 크기로 검사 (작업 트리의 파일 크기는 보지 않음)
+    pub async fn check(&self, files: Option<&[String]>) -> Result<Report> {
+        let mut report = Report::default();
+        let files = match files {
+            Some(files) => files.to_vec(),
+            None => Git::get_staged_files().await?,
+        };
+
+        let mut problems = Vec::new();
+        for (file, size) in Git::get_staged_sizes(&files).await? {
+            if size > self.max_size {
+                problems.push(format!(
+                    "  {} is {} (max {})",
+                    file.bold(),
+                    format_size(size),
+                    format_size(self.max_size)
+                ));
+            }
+        }
+
+        if self.reject_binary {
+            let allowed = FileMatcher::new(&self.allow_binary)?;
+            for file in Git::get_staged_binary_files(&files).await? {
+                if !allowed.matches(&file) {
+                    problems.push(format!("  {} is a binary file", file.bold()));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            return Ok(report);
+        }
+
+        report.error(format!("{}", "✖ staged files are too large or binary:".red()));
+        for problem in problems {
+            report.error(problem);
+        }
+        report.error(format!(
+            "{}",
+            "  unstage them with 'git restore --staged <file>', or use Git LFS".dimmed()
+        ));
+
+        Ok(report)
+    }
+}
+
+/// `1048576`, `"500KB"`, `"1.5 MB"` 같은 크기 표기를 바이트 수로 변환
+fn deserialize_size<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Size {
+        Bytes(u64),
+        Text(String),
+    }
+
+    match Size::deserialize(deserializer)? {
+        Size::Bytes(bytes) => Ok(bytes),
+        Size::Text(text) => parse_size(&text).map_err(serde::de::Error::custom),
+    }
+}
+
+fn parse_size(text: &str) -> Result<u64> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| anyhow!("Invalid size {}", text))?;
+    let multiplier = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "K" | "KB" | "KIB" => 1024,
+        "M" | "MB" | "MIB" => 1024 * 1024,
+        "G" | "GB" | "GIB" => 1024 * 1024 * 1024,
+        _ => return Err(anyhow!("Invalid size unit in {} (use B, KB, MB or GB)", text)),
+    };
+
+    Ok((number * multiplier as f64) as u64)
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+
+    format!("{:.1} {}", size, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+    use tokio::fs;
+
+    #[test]
+    fn test_parse_and_format_size() {
+        assert_eq!(parse_size("500").unwrap(), 500);
+        assert_eq!(parse_size("500KB").unwrap(), 500 * 1024);
+        assert_eq!(parse_size("1.5 MB").unwrap(), 1024 * 1024 * 3 / 2);
+        assert_eq!(parse_size("2g").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert!(parse_size("10 parsecs").is_err());
+        assert!(parse_size("MB").is_err());
+
+        assert_eq!(format_size(12), "12 B");
+        assert_eq!(format_size(500 * 1024), "500.0 KB");
+        assert_eq!(format_size(3 * 1024 * 1024 / 2), "1.5 MB");
+
+        let rules: LargeFilesRules = serde_json::from_str(r#"{ "max_size": "5MB" }"#).unwrap();
+        assert_eq!(rules.max_size, 5 * 1024 * 1024);
+        let rules: LargeFilesRules = serde_json::from_str(r#"{ "max_size": 100 }"#).unwrap();
+        assert_eq!(rules.max_size, 100);
+    }
+
+    async fn git(args: &[&str]) {
+        tokio::process::Command::new("git")
+            .args(args)
+            .output()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_check_uses_index_sizes_and_binary_allowlist() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("big.txt", "x".repeat(2048)).await.unwrap();
+        fs::write("small.txt", "small").await.unwrap();
+        fs::write("logo.png", [0x89, b'P', b'N', b'G', 0, 0, 0, 1]).await.unwrap();
+        fs::write("data.bin", [0u8, 1, 2, 3]).await.unwrap();
+        git(&["add", "."]).await;
+        // 작업 트리에서만 작아진 파일도 index 크기로 검사
+        fs::write("big.txt", "x").await.unwrap();
+
+        let rules = LargeFilesRules {
+            max_size: 1024,
+            ..LargeFilesRules::default()
+        };
+        let report = rules.check(None).await.unwrap();
+        assert!(!report.is_success());
+        let render = report.render();
+        assert!(render.contains("big.txt"));
+        assert!(render.contains("is 2.0 KB (max 1.0 KB)"));
+        assert!(!render.contains("small.txt"));
+        assert!(!render.contains("is a binary file"));
+
+        let rules = LargeFilesRules {
+            max_size: 4096,
+            reject_binary: true,
+            allow_binary: vec!["*.png".to_string()],
+        };
+        let render = rules.check(None).await.unwrap().render();
+        assert!(render.contains("data.bin"));
+        assert!(render.contains("is a binary file"));
+        assert!(!render.contains("logo.png"));
+
+        // glob 단계에서는 일치하는 파일만 검사
+        let report = rules.check(Some(&["small.txt".to_string()])).await.unwrap();
+        assert!(report.is_success());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -0,0 +1,353 @@
+//! 셸 명령어 대신 rusky 안에서 실행되는 내장 검사
+
+mod branch;
+mod conventional;
+mod hygiene;
+mod large_files;
+mod secrets;
+mod syntax;
+mod ticket;
+
+use anyhow::Result;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::context::HookContext;
+use crate::git::Git;
+
+pub use branch::BranchNameRules;
+pub use conventional::ConventionalCommitsRules;
+pub use hygiene::HygieneRules;
+pub use large_files::LargeFilesRules;
+pub use secrets::SecretsRules;
+pub use syntax::{Format, SyntaxRules};
+pub use ticket::TicketIdRules;
+
+/// 단계에서 실행할 내장 검사와 그 설정
+///
+/// `"builtin": "conventional-commits"`처럼 이름만 쓰면 기본 설정을 사용하고,
+/// `"builtin": { "type": "conventional-commits", ... }`로 설정을 바꿀 수 있습니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum Builtin {
+    ConventionalCommits(ConventionalCommitsRules),
+    BranchName(BranchNameRules),
+    TicketId(TicketIdRules),
+    Secrets(SecretsRules),
+    LargeFiles(LargeFilesRules),
+    Hygiene(HygieneRules),
+    Syntax(SyntaxRules),
+}
+
+impl Builtin {
+    /// 설정에 쓰는 검사 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            Builtin::ConventionalCommits(_) => "conventional-commits",
+            Builtin::BranchName(_) => "branch-name",
+            Builtin::TicketId(_) => "ticket-id",
+            Builtin::Secrets(_) => "secrets",
+            Builtin::LargeFiles(_) => "large-files",
+            Builtin::Hygiene(_) => "hygiene",
+            Builtin::Syntax(_) => "syntax",
+        }
+    }
+
+    /// 기본 설정을 그대로 쓰는지 확인
+    fn is_default(&self) -> bool {
+        match self {
+            Builtin::ConventionalCommits(rules) => *rules == ConventionalCommitsRules::default(),
+            Builtin::BranchName(rules) => *rules == BranchNameRules::default(),
+            Builtin::TicketId(rules) => *rules == TicketIdRules::default(),
+            Builtin::Secrets(rules) => *rules == SecretsRules::default(),
+            Builtin::LargeFiles(rules) => *rules == LargeFilesRules::default(),
+            Builtin::Hygiene(rules) => *rules == HygieneRules::default(),
+            Builtin::Syntax(rules) => *rules == SyntaxRules::default(),
+        }
+    }
+
+    /// 검사 실행
+    ///
+    /// `files`는 `glob`이 있는 단계에서 검사할 staged 파일입니다.
+    pub async fn run(&self, context: &HookContext, files: Option<&[String]>) -> Result<Report> {
+        match self {
+            Builtin::ConventionalCommits(rules) => rules.check(context).await,
+            Builtin::BranchName(rules) => rules.check(context).await,
+            Builtin::TicketId(rules) => rules.apply(context).await,
+            Builtin::Secrets(rules) => rules.check(files).await,
+            Builtin::LargeFiles(rules) => rules.check(files).await,
+            Builtin::Hygiene(rules) => rules.check(files).await,
+            Builtin::Syntax(rules) => rules.check(files).await,
+        }
+    }
+}
+
+/// `"conventional-commits"`와 `{ "type": "conventional-commits", ... }` 두 가지 표기를 모두 허용
+pub fn deserialize_builtin<'de, D>(deserializer: D) -> Result<Option<Builtin>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = match Option::<Value>::deserialize(deserializer)? {
+        None => return Ok(None),
+        Some(Value::String(name)) => serde_json::json!({ "type": name }),
+        Some(value) => value,
+    };
+
+    Builtin::deserialize(value)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+/// staged diff에서 추가된 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddedLine {
+    pub file: String,
+    /// staged 파일에서의 줄 번호 (1부터)
+    pub line: usize,
+    pub content: String,
+}
+
+/// `git diff -U0` 출력에서 추가된 줄만 골라내기
+pub fn added_lines(diff: &str) -> Vec<AddedLine> {
+    let mut lines = Vec::new();
+    let mut file: Option<String> = None;
+    let mut line = 0;
+    // 파일 헤더 (`diff --git`부터 첫 `@@`까지) 안인지 여부
+    let mut in_header = false;
+
+    for raw in diff.lines() {
+        if raw.starts_with("diff --git ") {
+            file = None;
+            in_header = true;
+        } else if in_header {
+            if let Some(path) = raw.strip_prefix("+++ ") {
+                // 공백이 있는 경로 뒤에는 탭이 붙음
+                file = unquote_path(path.trim_end_matches('\t'))
+                    .strip_prefix("b/")
+                    .map(String::from);
+            } else if raw.starts_with("@@ ") {
+                in_header = false;
+                line = hunk_start(raw);
+            }
+        } else if raw.starts_with("@@ ") {
+            line = hunk_start(raw);
+        } else if let (Some(content), Some(file)) = (raw.strip_prefix('+'), &file) {
+            lines.push(AddedLine {
+                file: file.clone(),
+                line,
+                content: content.to_string(),
+            });
+            line += 1;
+        }
+    }
+
+    lines
+}
+
+/// git이 C 문자열처럼 따옴표로 감싼 경로 (`"b/a\tb.env"`) 풀기
+///
+/// `core.quotePath=false`여도 `"`, `\`, 탭, 줄바꿈이 있는 경로는 따옴표로 감싸집니다.
+fn unquote_path(path: &str) -> String {
+    let Some(quoted) = path
+        .strip_prefix('"')
+        .and_then(|path| path.strip_suffix('"'))
+    else {
+        return path.to_string();
+    };
+
+    let mut bytes = Vec::new();
+    let mut rest = quoted.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        if byte != b'\\' {
+            bytes.push(byte);
+            continue;
+        }
+        let Some((&escaped, tail)) = rest.split_first() else {
+            bytes.push(byte);
+            break;
+        };
+        rest = tail;
+        match escaped {
+            b'a' => bytes.push(0x07),
+            b'b' => bytes.push(0x08),
+            b't' => bytes.push(b'\t'),
+            b'n' => bytes.push(b'\n'),
+            b'v' => bytes.push(0x0b),
+            b'f' => bytes.push(0x0c),
+            b'r' => bytes.push(b'\r'),
+            // UTF-8 등 비 ASCII 바이트는 세 자리 8진수 (`\303\251`)
+            b'0'..=b'7' if rest.len() >= 2 => {
+                let octal = [escaped, rest[0], rest[1]];
+                match u8::from_str_radix(std::str::from_utf8(&octal).unwrap_or_default(), 8) {
+                    Ok(value) => {
+                        bytes.push(value);
+                        rest = &rest[2..];
+                    }
+                    Err(_) => bytes.push(escaped),
+                }
+            }
+            other => bytes.push(other),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// `@@ -a,b +c,d @@`에서 새 파일의 시작 줄 번호
+fn hunk_start(hunk: &str) -> usize {
+    hunk.split_whitespace()
+        .find_map(|range| range.strip_prefix('+'))
+        .and_then(|range| range.split(',').next())
+        .and_then(|start| start.parse().ok())
+        .unwrap_or(0)
+}
+
+/// commit 메시지의 주석 문자 (`core.commentChar`, 기본값 `#`)
+async fn comment_char() -> char {
+    Git::get_config("core.commentChar")
+        .await
+        .ok()
+        .flatten()
+        .filter(|value| value != "auto")
+        .and_then(|value| value.chars().next())
+        .unwrap_or('#')
+}
+
+/// 기본 설정이면 이름만 저장
+pub fn serialize_builtin<S>(builtin: &Option<Builtin>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match builtin {
+        Some(builtin) if builtin.is_default() => serializer.serialize_str(builtin.name()),
+        _ => builtin.serialize(serializer),
+    }
+}
+
+/// 내장 검사가 출력할 내용과 성공 여부
+#[derive(Debug, Default)]
+pub struct Report {
+    lines: Vec<String>,
+    failed: bool,
+}
+
+impl Report {
+    /// 검사를 실패로 만드는 문제 추가
+    pub fn error(&mut self, message: impl Into<String>) {
+        self.failed = true;
+        self.lines.push(message.into());
+    }
+
+    /// 실패 여부와 상관없는 안내 추가
+    pub fn info(&mut self, message: impl Into<String>) {
+        self.lines.push(message.into());
+    }
+
+    pub fn is_success(&self) -> bool {
+        !self.failed
+    }
+
+    /// 터미널에 표시할 출력 (줄마다 개행 포함)
+    pub fn render(&self) -> String {
+        self.lines.iter().map(|line| format!("{}\n", line)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_builtin_short_and_full_forms() {
+        #[derive(Deserialize)]
+        struct Step {
+            #[serde(default, deserialize_with = "deserialize_builtin")]
+            builtin: Option<Builtin>,
+        }
+
+        let step: Step = serde_json::from_str(r#"{ "builtin": "conventional-commits" }"#).unwrap();
+        assert_eq!(
+            step.builtin,
+            Some(Builtin::ConventionalCommits(ConventionalCommitsRules::default()))
+        );
+
+        let step: Step = serde_json::from_str(
+            r#"{ "builtin": { "type": "conventional-commits", "types": ["feat"] } }"#,
+        )
+        .unwrap();
+        match step.builtin.unwrap() {
+            Builtin::ConventionalCommits(rules) => assert_eq!(rules.types, vec!["feat"]),
+            other => panic!("unexpected builtin {:?}", other),
+        }
+
+        let step: Step = serde_json::from_str("{}").unwrap();
+        assert!(step.builtin.is_none());
+
+        assert!(serde_json::from_str::<Step>(r#"{ "builtin": "unknown-check" }"#).is_err());
+    }
+
+    #[test]
+    fn test_added_lines() {
+        let diff = r#"diff --git a/src/main.rs b/src/main.rs
+index 1111111..2222222 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -3,0 +4,2 @@ fn main() {
++    let a = 1;
++    let b = 2;
+@@ -10 +12 @@ fn other() {
+-    old();
++    new();
+diff --git a/my file.txt b/my file.txt
+new file mode 100644
+--- /dev/null
++++ b/my file.txt
+@@ -0,0 +1 @@
++++ plus at start
+diff --git "a/a\tb.env" "b/a\tb.env"
+new file mode 100644
+--- /dev/null
++++ "b/a\tb.env"
+@@ -0,0 +1 @@
++TOKEN=secret
+diff --git a/logo.png b/logo.png
+Binary files /dev/null and b/logo.png differ
+"#;
+        let lines = added_lines(diff);
+        let summary: Vec<(&str, usize, &str)> = lines
+            .iter()
+            .map(|added| (added.file.as_str(), added.line, added.content.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("src/main.rs", 4, "    let a = 1;"),
+                ("src/main.rs", 5, "    let b = 2;"),
+                ("src/main.rs", 12, "    new();"),
+                ("my file.txt", 1, "++ plus at start"),
+                ("a\tb.env", 1, "TOKEN=secret"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unquote_path() {
+        assert_eq!(unquote_path("b/plain.txt"), "b/plain.txt");
+        assert_eq!(unquote_path(r#""b/a\tb""#), "b/a\tb");
+        assert_eq!(unquote_path(r#""b/say \"hi\"\\n""#), "b/say \"hi\"\\n");
+        assert_eq!(unquote_path(r#""b/line\nbreak""#), "b/line\nbreak");
+        assert_eq!(unquote_path(r#""b/caf\303\251""#), "b/café");
+    }
+
+    #[test]
+    fn test_report() {
+        let mut report = Report::default();
+        report.info("checked 2 files");
+        assert!(report.is_success());
+
+        report.error("bad file");
+        assert!(!report.is_success());
+        assert_eq!(report.render(), "checked 2 files\nbad file\n");
+    }
+}
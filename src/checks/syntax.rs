@@ -0,0 +1,310 @@
+use anyhow::Result;
+use colored::*;
+use regex::Regex;
+use serde::de::IgnoredAny;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use super::Report;
+use crate::git::Git;
+use crate::staged::FileMatcher;
+
+/// 검사할 수 있는 파일 형식
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Json,
+    Toml,
+    Yaml,
+}
+
+impl Format {
+    /// 확장자로 파일 형식 판단
+    fn of(path: &str) -> Option<Self> {
+        let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(Format::Json),
+            "toml" => Some(Format::Toml),
+            "yaml" | "yml" => Some(Format::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// staged 파일의 JSON/TOML/YAML 문법 검사 설정
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct SyntaxRules {
+    /// 검사할 형식
+    pub formats: Vec<Format>,
+    /// 검사하지 않을 파일 glob (주석을 허용하는 JSONC 설정 파일 등)
+    pub exclude: Vec<String>,
+}
+
+impl Default for SyntaxRules {
+    fn default() -> Self {
+        Self {
+            formats: vec![Format::Json, Format::Toml, Format::Yaml],
+            exclude: ["tsconfig*.json", "jsconfig*.json", ".vscode/*.json"]
+                .iter()
+                .map(|pattern| pattern.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// 문법 오류와 위치 (1부터 시작)
+#[derive(Debug, PartialEq)]
+struct SyntaxError {
+    line: usize,
+    column: usize,
+    message: String,
+}
+
+impl SyntaxRules {
+    /// index에 있는 staged 파일 내용을 형식에 맞게 파싱
+    pub async fn check(&self, files: Option<&[String]>) -> Result<Report> {
+        let mut report = Report::default();
+        let files = match files {
+            Some(files) => files.to_vec(),
+            None => Git::get_staged_files().await?,
+        };
+        let exclude = FileMatcher::new(&self.exclude)?;
+        let files: Vec<String> = files
+            .into_iter()
+            .filter(|file| !exclude.matches(file))
+            .filter(|file| Format::of(file).is_some_and(|format| self.formats.contains(&format)))
+            .collect();
+        if files.is_empty() {
+            return Ok(report);
+        }
+
+        let mut errors = Vec::new();
+        for blob in Git::get_staged_blobs(&files).await? {
+            let Some(format) = Format::of(&blob.path) else {
+                continue;
+            };
+            if let Err(error) = parse(format, &blob.content) {
+                errors.push(format!(
+                    "  {}:{}:{}: {}",
+                    blob.path.bold(),
+                    error.line,
+                    error.column,
+                    error.message
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(report);
+        }
+
+        report.error(format!("{}", "✖ syntax errors in staged files:".red()));
+        for error in errors {
+            report.error(error);
+        }
+
+        Ok(report)
+    }
+}
+
+/// 형식에 맞게 파싱하고 첫 번째 오류 반환
+fn parse(format: Format, content: &[u8]) -> Result<(), SyntaxError> {
+    let content = content
+        .strip_prefix("\u{feff}".as_bytes())
+        .unwrap_or(content);
+
+    match format {
+        Format::Json => serde_json::from_slice::<serde_json::Value>(content)
+            .map(|_| ())
+            .map_err(|error| SyntaxError {
+                line: error.line(),
+                column: error.column(),
+                message: strip_location(&error.to_string()),
+            }),
+        Format::Toml => {
+            let text = std::str::from_utf8(content).map_err(|error| {
+                let (line, column) = position(content, error.valid_up_to());
+                SyntaxError {
+                    line,
+                    column,
+                    message: "invalid UTF-8".to_string(),
+                }
+            })?;
+            text.parse::<toml::Table>().map(|_| ()).map_err(|error| {
+                let offset = error.span().map_or(0, |span| span.start);
+                let (line, column) = position(content, offset);
+                SyntaxError {
+                    line,
+                    column,
+                    message: error.message().trim().to_string(),
+                }
+            })
+        }
+        Format::Yaml => {
+            // `---`로 나뉜 여러 문서를 모두 검사
+            for document in serde_yaml::Deserializer::from_slice(content) {
+                if let Err(error) = IgnoredAny::deserialize(document) {
+                    let (line, column) = error
+                        .location()
+                        .map_or((1, 1), |location| (location.line(), location.column()));
+                    return Err(SyntaxError {
+                        line,
+                        column,
+                        message: strip_location(&error.to_string()),
+                    });
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// 바이트 위치의 줄과 열 (열은 문자 단위)
+fn position(content: &[u8], offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line_start = before
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |index| index + 1);
+    let line = before.iter().filter(|byte| **byte == b'\n').count() + 1;
+    let column = String::from_utf8_lossy(&before[line_start..])
+        .chars()
+        .count()
+        + 1;
+
+    (line, column)
+}
+
+/// 메시지에 붙은 ` at line 3 column 5` 제거 (위치는 따로 표시)
+fn strip_location(message: &str) -> String {
+    static LOCATION: OnceLock<Regex> = OnceLock::new();
+    LOCATION
+        .get_or_init(|| Regex::new(r" at line \d+ column \d+").unwrap())
+        .replace_all(message, "")
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+    use tokio::fs;
+
+    fn error_at(format: Format, content: &str) -> (usize, usize) {
+        let error = parse(format, content.as_bytes()).unwrap_err();
+        (error.line, error.column)
+    }
+
+    #[test]
+    fn test_format_of() {
+        assert_eq!(Format::of("package.json"), Some(Format::Json));
+        assert_eq!(Format::of("Cargo.toml"), Some(Format::Toml));
+        assert_eq!(Format::of(".github/workflows/ci.YML"), Some(Format::Yaml));
+        assert_eq!(Format::of("config.yaml"), Some(Format::Yaml));
+        assert_eq!(Format::of("Makefile"), None);
+    }
+
+    #[test]
+    fn test_parse_json() {
+        assert!(parse(Format::Json, br#"{ "a": [1, 2] }"#).is_ok());
+        assert!(parse(Format::Json, "\u{feff}{}".as_bytes()).is_ok());
+
+        assert_eq!(
+            error_at(Format::Json, "{\n  \"a\": 1\n  \"b\": 2\n}"),
+            (3, 3)
+        );
+        let error = parse(Format::Json, b"{\"a\": }").unwrap_err();
+        assert_eq!(error.message, "expected value");
+        assert!(parse(Format::Json, b"").is_err());
+    }
+
+    #[test]
+    fn test_parse_toml() {
+        assert!(parse(Format::Toml, b"[package]\nname = \"rusky\"\n").is_ok());
+        assert!(parse(Format::Toml, b"").is_ok());
+
+        assert_eq!(error_at(Format::Toml, "[package]\nname = \n"), (2, 8));
+        // 같은 키가 두 번 나오면 오류
+        let error = parse(Format::Toml, b"a = 1\na = 2\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(error.message.contains("duplicate key"));
+        assert_eq!(
+            parse(Format::Toml, b"a = \"\xff\"").unwrap_err().message,
+            "invalid UTF-8"
+        );
+    }
+
+    #[test]
+    fn test_parse_yaml() {
+        assert!(parse(Format::Yaml, b"a: 1\nb:\n  - x\n").is_ok());
+        assert!(parse(Format::Yaml, b"a: 1\n---\nb: 2\n").is_ok());
+        assert!(parse(Format::Yaml, b"Resource: !Ref Bucket\n").is_ok());
+        assert!(parse(Format::Yaml, b"").is_ok());
+
+        assert_eq!(error_at(Format::Yaml, "a: 1\n b: 2\n"), (2, 3));
+        // 두 번째 문서의 오류도 찾음
+        assert_eq!(error_at(Format::Yaml, "a: 1\n---\nb: [1, 2\n").0, 4);
+    }
+
+    #[test]
+    fn test_position_and_strip_location() {
+        assert_eq!(position(b"ab\ncd", 0), (1, 1));
+        assert_eq!(position(b"ab\ncd", 4), (2, 2));
+        assert_eq!(position("한글\nx".as_bytes(), 6), (1, 3));
+        assert_eq!(
+            strip_location("expected value at line 1 column 7"),
+            "expected value"
+        );
+        assert_eq!(
+            strip_location("did not find expected ',' at line 2 column 1, while parsing"),
+            "did not find expected ',', while parsing"
+        );
+        assert_eq!(strip_location("no location"), "no location");
+    }
+
+    async fn git(args: &[&str]) {
+        tokio::process::Command::new("git")
+            .args(args)
+            .output()
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_check_staged_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("ok.json", "{}").await.unwrap();
+        fs::write("bad.json", "{\"a\": 1,}").await.unwrap();
+        fs::write("bad.yml", "a: [1\n").await.unwrap();
+        fs::write("tsconfig.json", "{ // comment\n}").await.unwrap();
+        git(&["add", "."]).await;
+        // 작업 트리에서만 고친 파일은 여전히 실패
+        fs::write("bad.json", "{\"a\": 1}").await.unwrap();
+
+        let report = SyntaxRules::default().check(None).await.unwrap();
+        assert!(!report.is_success());
+        let render = report.render();
+        assert!(render.contains("bad.json"));
+        assert!(render.contains(":1:9: trailing comma"));
+        assert!(render.contains("bad.yml"));
+        assert!(!render.contains("ok.json"));
+        assert!(!render.contains("tsconfig.json"));
+
+        let json_only = SyntaxRules {
+            formats: vec![Format::Json],
+            ..SyntaxRules::default()
+        };
+        let render = json_only.check(None).await.unwrap().render();
+        assert!(!render.contains("bad.yml"));
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -0,0 +1,386 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use super::{comment_char, Report};
+use crate::context::HookContext;
+use crate::git::Git;
+
+/// 메시지를 git이 만든 경우 (`git merge`, `git merge --squash`)의 출처
+const SKIPPED_SOURCES: &[&str] = &["merge", "squash"];
+
+/// 티켓 번호를 넣을 위치
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TicketPosition {
+    Prefix,
+    Suffix,
+    /// 메시지 끝의 trailer 문단 (`Refs: ABC-123`)
+    Trailer,
+}
+
+/// 브랜치 이름의 티켓 번호를 commit 메시지에 넣는 설정
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(default)]
+pub struct TicketIdRules {
+    /// 브랜치 이름에서 티켓 번호를 찾는 정규식 (그룹이 있으면 첫 번째 그룹 사용)
+    pub pattern: String,
+    pub position: TicketPosition,
+    /// 메시지에 넣을 형식 (`{ticket}`이 티켓 번호로 바뀜)
+    pub format: String,
+}
+
+impl Default for TicketIdRules {
+    fn default() -> Self {
+        Self {
+            pattern: r"[A-Z][A-Z0-9]+-\d+".to_string(),
+            // 첫 줄을 바꾸지 않아야 conventional-commits 검사와 함께 쓸 수 있음
+            position: TicketPosition::Trailer,
+            format: "Refs: {ticket}".to_string(),
+        }
+    }
+}
+
+impl TicketIdRules {
+    /// `prepare-commit-msg`의 메시지 파일에 티켓 번호 넣기
+    pub async fn apply(&self, context: &HookContext) -> Result<Report> {
+        let mut report = Report::default();
+
+        let message_file = context.message_file().ok_or_else(|| {
+            anyhow!(
+                "ticket-id needs a commit message file; use it in the prepare-commit-msg hook (not {})",
+                context.hook_name
+            )
+        })?;
+
+        if context
+            .commit_source()
+            .is_some_and(|source| SKIPPED_SOURCES.contains(&source))
+        {
+            report.info(format!("{}", "○ skipped (merge or squash message)".dimmed()));
+            return Ok(report);
+        }
+
+        let Some(branch) = Git::get_current_branch().await? else {
+            report.info(format!("{}", "○ skipped (detached HEAD)".dimmed()));
+            return Ok(report);
+        };
+        let Some(ticket) = self.find_ticket(&branch)? else {
+            report.info(format!("{}", format!("○ no ticket in branch {}", branch).dimmed()));
+            return Ok(report);
+        };
+
+        let message = fs::read_to_string(message_file).await?;
+        match self.insert(&message, &ticket, comment_char().await) {
+            Some(updated) => {
+                fs::write(message_file, updated).await?;
+                report.info(format!("🎫 added {} to the commit message", ticket));
+            }
+            None => report.info(format!(
+                "{}",
+                format!("○ {} is already in the commit message", ticket).dimmed()
+            )),
+        }
+
+        Ok(report)
+    }
+
+    /// 브랜치 이름에서 티켓 번호 찾기
+    fn find_ticket(&self, branch: &str) -> Result<Option<String>> {
+        let regex = Regex::new(&self.pattern)
+            .map_err(|e| anyhow!("Invalid ticket pattern {}: {}", self.pattern, e))?;
+
+        Ok(regex.captures(branch).map(|captures| {
+            captures
+                .get(1)
+                .or_else(|| captures.get(0))
+                .map(|ticket| ticket.as_str().to_string())
+                .unwrap_or_default()
+        }))
+    }
+
+    /// 티켓 번호를 넣은 메시지 (이미 있으면 None)
+    fn insert(&self, message: &str, ticket: &str, comment_char: char) -> Option<String> {
+        let mut lines: Vec<&str> = message.split_inclusive('\n').collect();
+
+        let has_ticket = lines
+            .iter()
+            .filter(|line| !line.starts_with(comment_char))
+            .any(|line| line.contains(ticket));
+        if has_ticket {
+            return None;
+        }
+
+        let tag = self.format.replace("{ticket}", ticket);
+        if self.position == TicketPosition::Trailer {
+            return Some(insert_trailer(&lines, &tag, comment_char));
+        }
+
+        let index = lines
+            .iter()
+            .position(|line| !line.starts_with(comment_char))
+            .unwrap_or(lines.len());
+
+        let line = lines.get(index).copied().unwrap_or_default();
+        let subject = line.trim_end_matches(['\n', '\r']);
+        let ending = &line[subject.len()..];
+
+        let subject = if subject.trim().is_empty() {
+            // 편집기에서 메시지를 쓸 수 있도록 빈 첫 줄에는 티켓 번호만 넣음
+            format!("{} ", tag)
+        } else {
+            match self.position {
+                TicketPosition::Prefix => format!("{} {}", tag, subject),
+                TicketPosition::Suffix => format!("{} {}", subject, tag),
+                TicketPosition::Trailer => unreachable!("trailers are added to the last paragraph"),
+            }
+        };
+        let updated = format!("{}{}", subject, if ending.is_empty() { "\n" } else { ending });
+
+        if index < lines.len() {
+            lines[index] = &updated;
+        } else {
+            lines.insert(0, &updated);
+        }
+        Some(lines.concat())
+    }
+}
+
+/// 메시지 끝의 trailer 문단에 한 줄 추가 (주석과 `git commit -v`의 diff는 그대로 둠)
+fn insert_trailer(lines: &[&str], trailer: &str, comment_char: char) -> String {
+    let end = lines
+        .iter()
+        .position(|line| line.starts_with(comment_char))
+        .unwrap_or(lines.len());
+    let (message, rest) = lines.split_at(end);
+
+    let Some(last) = message.iter().rposition(|line| !line.trim().is_empty()) else {
+        // 편집기에서 첫 줄에 제목을 쓸 수 있도록 비워 둠
+        return format!("\n\n{}\n{}", trailer, rest.concat());
+    };
+    let first = message
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .unwrap_or_default();
+    let paragraph = message[..last]
+        .iter()
+        .rposition(|line| line.trim().is_empty())
+        .map_or(first, |blank| blank + 1);
+    // 제목만 있는 문단 (`feat: add`)은 trailer 문단이 아님
+    let has_trailers = paragraph > first
+        && message[paragraph..=last]
+            .iter()
+            .all(|line| is_trailer(line));
+
+    let ending = if message[last].ends_with("\r\n") { "\r\n" } else { "\n" };
+    let mut updated = message[..=last].concat();
+    if !updated.ends_with('\n') {
+        updated.push_str(ending);
+    }
+    if !has_trailers {
+        updated.push_str(ending);
+    }
+    updated.push_str(trailer);
+    updated.push_str(ending);
+    updated.push_str(&message[last + 1..].concat());
+    updated.push_str(&rest.concat());
+    updated
+}
+
+/// `Token: value` 형식의 trailer 줄인지 확인
+fn is_trailer(line: &str) -> bool {
+    line.split_once(": ").is_some_and(|(token, _)| {
+        token == "BREAKING CHANGE"
+            || (!token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_ticket() {
+        let rules = TicketIdRules::default();
+        assert_eq!(
+            rules.find_ticket("feat/ABC-123-add-doctor").unwrap(),
+            Some("ABC-123".to_string())
+        );
+        assert_eq!(rules.find_ticket("fix-typo").unwrap(), None);
+
+        let grouped = TicketIdRules {
+            pattern: r"^\w+/(\d+)-".to_string(),
+            ..TicketIdRules::default()
+        };
+        assert_eq!(grouped.find_ticket("feat/42-x").unwrap(), Some("42".to_string()));
+
+        let invalid = TicketIdRules {
+            pattern: "(".to_string(),
+            ..TicketIdRules::default()
+        };
+        assert!(invalid.find_ticket("x").is_err());
+    }
+
+    #[test]
+    fn test_insert() {
+        let rules = TicketIdRules {
+            position: TicketPosition::Prefix,
+            format: "[{ticket}]".to_string(),
+            ..TicketIdRules::default()
+        };
+        assert_eq!(
+            rules.insert("feat: add\n\nbody\n", "ABC-1", '#').unwrap(),
+            "[ABC-1] feat: add\n\nbody\n"
+        );
+        assert_eq!(rules.insert("feat: add", "ABC-1", '#').unwrap(), "[ABC-1] feat: add\n");
+        // git commit (메시지 없이)의 템플릿
+        assert_eq!(
+            rules.insert("\n# Please enter the commit message\n", "ABC-1", '#').unwrap(),
+            "[ABC-1] \n# Please enter the commit message\n"
+        );
+        assert_eq!(
+            rules.insert("# comment only\n", "ABC-1", '#').unwrap(),
+            "[ABC-1] \n# comment only\n"
+        );
+        // 이미 있으면 그대로 (주석 안의 번호는 무시)
+        assert_eq!(rules.insert("fix: typo\n\nRefs: ABC-1\n", "ABC-1", '#'), None);
+        assert!(rules.insert("fix: typo\n# on branch ABC-1\n", "ABC-1", '#').is_some());
+
+        let suffix = TicketIdRules {
+            position: TicketPosition::Suffix,
+            format: "({ticket})".to_string(),
+            ..TicketIdRules::default()
+        };
+        assert_eq!(suffix.insert("fix: typo\r\n", "ABC-1", '#').unwrap(), "fix: typo (ABC-1)\r\n");
+    }
+
+    #[test]
+    fn test_insert_trailer() {
+        let rules = TicketIdRules::default();
+        assert_eq!(
+            rules.insert("feat: add", "ABC-1", '#').unwrap(),
+            "feat: add\n\nRefs: ABC-1\n"
+        );
+        assert_eq!(
+            rules.insert("feat: add\n\nbody\n\n", "ABC-1", '#').unwrap(),
+            "feat: add\n\nbody\n\nRefs: ABC-1\n\n"
+        );
+        // 이미 있는 trailer 문단에 이어서 추가
+        assert_eq!(
+            rules
+                .insert("feat: add\n\nSigned-off-by: A <a@b>\n# comment\n", "ABC-1", '#')
+                .unwrap(),
+            "feat: add\n\nSigned-off-by: A <a@b>\nRefs: ABC-1\n# comment\n"
+        );
+        // git commit (메시지 없이)의 템플릿: 첫 줄은 제목을 쓰도록 비워 둠
+        assert_eq!(
+            rules.insert("\n# Please enter the commit message\n", "ABC-1", '#').unwrap(),
+            "\n\nRefs: ABC-1\n# Please enter the commit message\n"
+        );
+        assert_eq!(
+            rules.insert("fix: typo\r\n", "ABC-1", '#').unwrap(),
+            "fix: typo\r\n\r\nRefs: ABC-1\r\n"
+        );
+    }
+
+    async fn setup_repo_on_branch(branch: &str) -> (TempDir, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        tokio::process::Command::new("git")
+            .args(["init", "-q", "-b", branch])
+            .output()
+            .await
+            .unwrap();
+
+        (temp_dir, original_dir)
+    }
+
+    fn context(args: &[&str]) -> HookContext {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        HookContext::new("prepare-commit-msg", &args)
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_apply_to_message_file() {
+        let (_temp_dir, original_dir) = setup_repo_on_branch("feat/ABC-123-tickets").await;
+        let rules = TicketIdRules::default();
+
+        fs::write("MSG", "feat: add tickets\n").await.unwrap();
+        let report = rules.apply(&context(&["MSG", "message"])).await.unwrap();
+        assert!(report.is_success());
+        assert_eq!(
+            fs::read_to_string("MSG").await.unwrap(),
+            "feat: add tickets\n\nRefs: ABC-123\n"
+        );
+
+        // 두 번 실행해도 한 번만 들어감 (예: --amend)
+        rules.apply(&context(&["MSG", "commit", "HEAD"])).await.unwrap();
+        assert_eq!(
+            fs::read_to_string("MSG").await.unwrap(),
+            "feat: add tickets\n\nRefs: ABC-123\n"
+        );
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_apply_skips_merge_and_squash() {
+        let (_temp_dir, original_dir) = setup_repo_on_branch("feat/ABC-123-tickets").await;
+        let rules = TicketIdRules::default();
+
+        for source in ["merge", "squash"] {
+            let message = "Merge branch 'main'\n";
+            fs::write("MSG", message).await.unwrap();
+            rules.apply(&context(&["MSG", source])).await.unwrap();
+            assert_eq!(fs::read_to_string("MSG").await.unwrap(), message);
+        }
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_apply_without_ticket_in_branch() {
+        let (_temp_dir, original_dir) = setup_repo_on_branch("main").await;
+
+        fs::write("MSG", "fix: typo\n").await.unwrap();
+        let report = TicketIdRules::default().apply(&context(&["MSG"])).await.unwrap();
+        assert!(report.is_success());
+        assert_eq!(fs::read_to_string("MSG").await.unwrap(), "fix: typo\n");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_default_ticket_passes_conventional_commits() {
+        let (_temp_dir, original_dir) = setup_repo_on_branch("feat/ABC-123-tickets").await;
+        let commit_msg = HookContext::new("commit-msg", &["MSG".to_string()]);
+        let conventional = super::super::ConventionalCommitsRules::default();
+
+        for message in ["feat: add tickets\n", "\n# Please enter the commit message\n"] {
+            fs::write("MSG", message).await.unwrap();
+            TicketIdRules::default().apply(&context(&["MSG"])).await.unwrap();
+            if message.starts_with('\n') {
+                // 편집기에서 비워 둔 첫 줄에 제목을 씀
+                let edited = fs::read_to_string("MSG").await.unwrap();
+                fs::write("MSG", format!("feat: add tickets{}", edited)).await.unwrap();
+            }
+
+            let report = conventional.check(&commit_msg).await.unwrap();
+            assert!(report.is_success(), "{}", report.render());
+            assert!(fs::read_to_string("MSG").await.unwrap().contains("Refs: ABC-123"));
+        }
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
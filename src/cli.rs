@@ -3,9 +3,16 @@ use colored::*;
 use std::path::Path;
 use tokio::fs;
 
-use crate::config::Config;
+use crate::cache::ResultCache;
+use crate::config::{Config, HookCommand};
+use crate::context::HookContext;
+use crate::doctor::{Diagnosis, Doctor};
 use crate::git::Git;
 use crate::hooks::HookManager;
+use crate::import::{Migration, Tool};
+use crate::runner::{HookRunner, RunOptions};
+use crate::staged::FileMatcher;
+use crate::stash::UnstagedStash;
 
 pub async fn init() -> Result<()> {
     // Git 저장소인지 확인
@@ -40,7 +47,12 @@ pub async fn init() -> Result<()> {
     Ok(())
 }
 
-pub async fn add_hook(hook_name: &str, command: &str) -> Result<()> {
+pub async fn add_hook(
+    hook_name: &str,
+    command: &str,
+    name: Option<&str>,
+    globs: &[String],
+) -> Result<()> {
     let mut config = Config::load().await?;
 
     // 유효한 hook 이름인지 확인
@@ -48,29 +60,78 @@ pub async fn add_hook(hook_name: &str, command: &str) -> Result<()> {
         return Err(anyhow!("Invalid hook name: {}", hook_name));
     }
 
-    config.add_hook(hook_name.to_string(), command.to_string());
+    let (step_name, replaced) = match name {
+        Some(name) => {
+            let replaced =
+                config.add_step(hook_name.to_string(), name.to_string(), command.to_string());
+            (name.to_string(), replaced)
+        }
+        None => (
+            config.add_hook(hook_name.to_string(), command.to_string()),
+            false,
+        ),
+    };
+
+    if !globs.is_empty() {
+        // 잘못된 패턴은 저장하기 전에 거부
+        FileMatcher::new(globs)?;
+        if let Some(step) = config
+            .hooks
+            .get_mut(hook_name)
+            .and_then(|hook| hook.get_command_mut(&step_name))
+        {
+            step.glob = globs.to_vec();
+        }
+    }
     config.save().await?;
 
     // Hook 파일 생성
-    HookManager::create_hook_file(hook_name, command).await?;
+    HookManager::prepare_hooks_dir(&config, false).await?;
+    HookManager::create_hook_file(hook_name).await?;
 
+    let action = if replaced { "Updated" } else { "Added" };
     println!(
         "{}",
-        format!("✅ Added {} hook: {}", hook_name, command).green()
+        format!("✅ {} {} hook step {}: {}", action, hook_name, step_name, command).green()
     );
 
     Ok(())
 }
 
-pub async fn remove_hook(hook_name: &str) -> Result<()> {
+pub async fn remove_hook(hook_name: &str, step: Option<&str>) -> Result<()> {
     let mut config = Config::load().await?;
 
-    if config.remove_hook(hook_name) {
-        config.save().await?;
-        HookManager::remove_hook_file(hook_name).await?;
-        println!("{}", format!("✅ Removed {} hook", hook_name).green());
-    } else {
-        println!("{}", format!("⚠️  Hook {} not found", hook_name).yellow());
+    match step {
+        Some(step) => {
+            if !config.remove_step(hook_name, step) {
+                println!(
+                    "{}",
+                    format!("⚠️  Step {} not found in {} hook", step, hook_name).yellow()
+                );
+                return Ok(());
+            }
+
+            config.save().await?;
+            println!(
+                "{}",
+                format!("✅ Removed step {} from {} hook", step, hook_name).green()
+            );
+
+            // 마지막 단계였다면 hook 파일도 제거
+            if config.get_hook(hook_name).is_none() {
+                HookManager::remove_hook_file(hook_name).await?;
+                println!("{}", format!("✅ Removed {} hook", hook_name).green());
+            }
+        }
+        None => {
+            if config.remove_hook(hook_name) {
+                config.save().await?;
+                HookManager::remove_hook_file(hook_name).await?;
+                println!("{}", format!("✅ Removed {} hook", hook_name).green());
+            } else {
+                println!("{}", format!("⚠️  Hook {} not found", hook_name).yellow());
+            }
+        }
     }
 
     Ok(())
@@ -85,23 +146,61 @@ pub async fn list_hooks() -> Result<()> {
     }
 
     println!("{}", "Configured hooks:".bold());
-    for (hook_name, command) in &config.hooks {
-        println!(
-            "  {} {}: {}",
-            "•".blue(),
-            hook_name.bold(),
-            command.dimmed()
-        );
+    for (hook_name, hook) in &config.hooks {
+        if let [step] = hook.commands.as_slice() {
+            println!(
+                "  {} {}: {}",
+                "•".blue(),
+                hook_name.bold(),
+                describe_step(step).dimmed()
+            );
+            continue;
+        }
+
+        println!("  {} {}", "•".blue(), hook_name.bold());
+        for step in &hook.commands {
+            println!("      - {}: {}", step.name, describe_step(step).dimmed());
+        }
     }
 
     Ok(())
 }
 
-pub async fn install_hooks() -> Result<()> {
-    let config = Config::load().await?;
+/// 목록에 표시할 단계 설명
+fn describe_step(step: &HookCommand) -> String {
+    let run = match &step.builtin {
+        Some(builtin) => format!("builtin {}", builtin.name()),
+        None => step.run.clone(),
+    };
+
+    if step.glob.is_empty() {
+        run
+    } else {
+        format!("{} [{}]", run, step.glob.join(", "))
+    }
+}
+
+pub async fn install_hooks(hooks_path: Option<&str>, force: bool) -> Result<()> {
+    let mut config = Config::load().await?;
 
-    for (hook_name, command) in &config.hooks {
-        HookManager::create_hook_file(hook_name, command).await?;
+    if let Some(hooks_path) = hooks_path {
+        config.hooks_path = Some(hooks_path.to_string());
+    }
+
+    // core.hooksPath를 넘겨받지 못하면 설정도 바꾸지 않음
+    HookManager::prepare_hooks_dir(&config, force).await?;
+    if hooks_path.is_some() {
+        config.save().await?;
+    }
+    if let Some(hooks_path) = &config.hooks_path {
+        println!(
+            "{}",
+            format!("✅ Set core.hooksPath to {}", hooks_path).green()
+        );
+    }
+
+    for hook_name in config.hooks.keys() {
+        HookManager::create_hook_file(hook_name).await?;
     }
 
     println!(
@@ -118,13 +217,131 @@ pub async fn uninstall_hooks() -> Result<()> {
     for hook_name in config.hooks.keys() {
         HookManager::remove_hook_file(hook_name).await?;
     }
+    HookManager::release_hooks_path(&config).await?;
 
     println!("{}", "✅ Uninstalled all hooks".green());
 
     Ok(())
 }
 
-fn is_valid_hook_name(hook_name: &str) -> bool {
+pub async fn run_hook(hook_name: &str, args: &[String], explain: bool) -> Result<i32> {
+    let context = HookContext::read(hook_name, args).await?;
+    HookRunner::run(&context, RunOptions { explain }).await
+}
+
+pub async fn restore() -> Result<()> {
+    if UnstagedStash::recover().await? {
+        println!("{}", "✅ Restored changes hidden by an interrupted hook run".green());
+    } else {
+        println!("{}", "Nothing to restore".dimmed());
+    }
+
+    Ok(())
+}
+
+/// 저장소의 캐시된 단계 결과를 모두 삭제
+pub async fn clear_cache() -> Result<()> {
+    if let Ok(git_root) = Git::get_git_root().await {
+        std::env::set_current_dir(git_root)?;
+    }
+
+    match ResultCache::clear().await? {
+        0 => println!("{}", "Nothing to clear".dimmed()),
+        removed => println!(
+            "{}",
+            format!("✅ Removed {} cached result(s)", removed).green()
+        ),
+    }
+
+    Ok(())
+}
+
+/// 설치 상태 진단, 문제가 없으면 (또는 모두 고쳤으면) true 반환
+pub async fn doctor(fix: bool) -> Result<bool> {
+    let config = Config::load().await?;
+
+    let mut diagnoses = Doctor::diagnose(&config).await?;
+    Doctor::print_report(&diagnoses);
+
+    if fix && !diagnoses.iter().all(Diagnosis::is_healthy) {
+        Doctor::fix(&config, &diagnoses).await?;
+        println!();
+        println!("{}", "🔧 Fixed problems, checking again...".green());
+        diagnoses = Doctor::diagnose(&config).await?;
+        Doctor::print_report(&diagnoses);
+    }
+
+    let healthy = diagnoses.iter().all(Diagnosis::is_healthy);
+    if healthy {
+        println!("{}", "✅ All hooks are installed correctly".green());
+    } else if !fix {
+        println!(
+            "{}",
+            "Run 'rusky doctor --fix' to repair these problems".yellow()
+        );
+    }
+
+    Ok(healthy)
+}
+
+/// 다른 도구의 hook 설정을 rusky 설정으로 옮기고 hook 설치
+pub async fn import(tool: Tool) -> Result<()> {
+    if !Git::is_git_repo().await? {
+        return Err(anyhow!(
+            "Not a git repository. Please run 'git init' first."
+        ));
+    }
+
+    let migration = tool.read().await?;
+
+    let mut config = Config::load().await?;
+    for (hook_name, imported) in &migration.hooks {
+        let added = imported
+            .commands
+            .iter()
+            .filter(|step| config.add_command(hook_name, (*step).clone()))
+            .count();
+        if let Some(hook) = config.hooks.get_mut(hook_name) {
+            hook.parallel |= imported.parallel;
+            hook.stash |= imported.stash;
+        }
+        if added == 0 {
+            println!("{}", format!("○ {} is already configured", hook_name).dimmed());
+            continue;
+        }
+
+        let steps: Vec<String> = imported.commands.iter().map(describe_step).collect();
+        println!(
+            "{}",
+            format!("✅ Imported {}: {}", hook_name, steps.join(", ").replace('\n', "; ")).green()
+        );
+    }
+    config.save().await?;
+
+    for done in tool.release().await? {
+        println!("{}", format!("✅ {}", done).green());
+    }
+
+    install_hooks(None, false).await?;
+    print_migration_notes(&migration);
+
+    Ok(())
+}
+
+/// 직접 확인해야 할 내용 출력
+fn print_migration_notes(migration: &Migration) {
+    if migration.notes.is_empty() {
+        println!("{}", "\n🎉 Import finished, nothing needs manual review".bold().green());
+        return;
+    }
+
+    println!("{}", "\n⚠️  Needs manual review:".yellow().bold());
+    for note in &migration.notes {
+        println!("  {} {}", "•".yellow(), note);
+    }
+}
+
+pub fn is_valid_hook_name(hook_name: &str) -> bool {
     const VALID_HOOKS: &[&str] = &[
         "applypatch-msg",
         "pre-applypatch",
@@ -152,6 +369,7 @@ fn is_valid_hook_name(hook_name: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
     use tempfile::TempDir;
     use std::env;
 
@@ -164,7 +382,7 @@ mod tests {
         
         // Git 저장소 초기화
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
@@ -189,6 +407,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_init_success() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
@@ -203,6 +422,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_init_not_git_repo() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -218,18 +438,20 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_add_hook_success() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
         // init 먼저 실행
         init().await.unwrap();
         
-        let result = add_hook("pre-commit", "echo 'test'").await;
+        let result = add_hook("pre-commit", "echo 'test'", None, &[]).await;
         assert!(result.is_ok());
         
         // 설정 파일에 hook이 추가되었는지 확인
         let config = Config::load().await.unwrap();
-        assert_eq!(config.hooks.get("pre-commit"), Some(&"echo 'test'".to_string()));
+        let hook = config.get_hook("pre-commit").unwrap();
+        assert_eq!(hook.commands[0].run, "echo 'test'");
         
         // Hook 파일이 생성되었는지 확인
         let hook_path = Git::get_hook_path("pre-commit").await.unwrap();
@@ -239,12 +461,13 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_add_hook_invalid_name() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
         init().await.unwrap();
         
-        let result = add_hook("invalid-hook", "echo 'test'").await;
+        let result = add_hook("invalid-hook", "echo 'test'", None, &[]).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid hook name"));
         
@@ -252,13 +475,14 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_remove_hook_success() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
         init().await.unwrap();
-        add_hook("pre-commit", "echo 'test'").await.unwrap();
+        add_hook("pre-commit", "echo 'test'", None, &[]).await.unwrap();
         
-        let result = remove_hook("pre-commit").await;
+        let result = remove_hook("pre-commit", None).await;
         assert!(result.is_ok());
         
         // 설정 파일에서 hook이 제거되었는지 확인
@@ -273,18 +497,65 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
+    async fn test_add_and_remove_named_steps() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        
+        init().await.unwrap();
+        add_hook("pre-commit", "cargo fmt --check", Some("fmt"), &[]).await.unwrap();
+        add_hook("pre-commit", "cargo clippy", Some("clippy"), &[]).await.unwrap();
+        
+        let config = Config::load().await.unwrap();
+        assert_eq!(config.get_hook("pre-commit").unwrap().commands.len(), 2);
+        
+        // 단계 하나만 제거하면 hook 파일은 유지됨
+        remove_hook("pre-commit", Some("fmt")).await.unwrap();
+        let hook_path = Git::get_hook_path("pre-commit").await.unwrap();
+        assert!(hook_path.exists());
+        
+        // 마지막 단계를 제거하면 hook 파일도 삭제됨
+        remove_hook("pre-commit", Some("clippy")).await.unwrap();
+        assert!(!hook_path.exists());
+        
+        cleanup_test_env(original_dir);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_add_hook_with_glob() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        
+        init().await.unwrap();
+        let globs = vec!["*.rs".to_string()];
+        add_hook("pre-commit", "rustfmt --check", Some("fmt"), &globs).await.unwrap();
+        
+        let config = Config::load().await.unwrap();
+        let step = config.get_hook("pre-commit").unwrap().get_command("fmt").unwrap();
+        assert_eq!(step.glob, globs);
+        
+        // 잘못된 glob 패턴은 거부됨
+        let invalid = vec!["src/[".to_string()];
+        let result = add_hook("pre-commit", "eslint", Some("lint"), &invalid).await;
+        assert!(result.is_err());
+        
+        cleanup_test_env(original_dir);
+    }
+
+    #[tokio::test]
+    #[serial]
     async fn test_remove_hook_not_found() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
         init().await.unwrap();
         
-        let result = remove_hook("non-existent").await;
+        let result = remove_hook("non-existent", None).await;
         assert!(result.is_ok()); // 에러가 발생하지 않아야 함
         
         cleanup_test_env(original_dir);
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_list_hooks_empty() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
@@ -297,12 +568,13 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_list_hooks_with_content() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
         init().await.unwrap();
-        add_hook("pre-commit", "echo 'pre-commit'").await.unwrap();
-        add_hook("pre-push", "echo 'pre-push'").await.unwrap();
+        add_hook("pre-commit", "echo 'pre-commit'", None, &[]).await.unwrap();
+        add_hook("pre-push", "echo 'pre-push'", None, &[]).await.unwrap();
         
         let result = list_hooks().await;
         assert!(result.is_ok());
@@ -311,6 +583,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_install_hooks() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
@@ -321,7 +594,7 @@ mod tests {
         config.add_hook("pre-commit".to_string(), "echo 'test'".to_string());
         config.save().await.unwrap();
         
-        let result = install_hooks().await;
+        let result = install_hooks(None, false).await;
         assert!(result.is_ok());
         
         // Hook 파일이 생성되었는지 확인
@@ -332,12 +605,67 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
+    async fn test_install_hooks_with_hooks_path() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        
+        init().await.unwrap();
+        add_hook("pre-commit", "echo 'test'", None, &[]).await.unwrap();
+        
+        install_hooks(Some(".rusky/_"), false).await.unwrap();
+        
+        let config = Config::load().await.unwrap();
+        assert_eq!(config.hooks_path, Some(".rusky/_".to_string()));
+        assert_eq!(
+            Git::get_config("core.hooksPath").await.unwrap(),
+            Some(".rusky/_".to_string())
+        );
+        assert!(Path::new(".rusky/_/pre-commit").exists());
+        
+        // 이후 추가한 hook도 같은 디렉토리에 설치됨
+        add_hook("pre-push", "echo 'test'", None, &[]).await.unwrap();
+        assert!(Path::new(".rusky/_/pre-push").exists());
+        
+        uninstall_hooks().await.unwrap();
+        assert_eq!(Git::get_config("core.hooksPath").await.unwrap(), None);
+        assert!(!Path::new(".rusky/_/pre-commit").exists());
+        
+        cleanup_test_env(original_dir);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_install_hooks_path_refused_keeps_config() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        init().await.unwrap();
+        Git::set_config("core.hooksPath", ".husky").await.unwrap();
+
+        // 다른 도구의 core.hooksPath를 넘겨받지 못하면 설정도 그대로
+        assert!(install_hooks(Some(".rusky/_"), false).await.is_err());
+        assert_eq!(Config::load().await.unwrap().hooks_path, None);
+        assert_eq!(
+            Git::get_config("core.hooksPath").await.unwrap(),
+            Some(".husky".to_string())
+        );
+
+        install_hooks(Some(".rusky/_"), true).await.unwrap();
+        assert_eq!(
+            Config::load().await.unwrap().hooks_path,
+            Some(".rusky/_".to_string())
+        );
+
+        cleanup_test_env(original_dir);
+    }
+
+    #[tokio::test]
+    #[serial]
     async fn test_uninstall_hooks() {
         let (_temp_dir, original_dir) = setup_test_git_repo().await;
         
         init().await.unwrap();
-        add_hook("pre-commit", "echo 'test'").await.unwrap();
-        add_hook("pre-push", "echo 'test'").await.unwrap();
+        add_hook("pre-commit", "echo 'test'", None, &[]).await.unwrap();
+        add_hook("pre-push", "echo 'test'", None, &[]).await.unwrap();
         
         let result = uninstall_hooks().await;
         assert!(result.is_ok());
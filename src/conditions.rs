@@ -0,0 +1,457 @@
+//! 단계를 실행할지 정하는 `when`/`skip` 조건
+
+use anyhow::{anyhow, Result};
+use globset::{GlobBuilder, GlobSetBuilder};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::config::deserialize_one_or_many;
+use crate::context::{is_zero_sha, HookContext};
+use crate::git::Git;
+use crate::staged::FileMatcher;
+
+/// 단계의 실행 조건
+///
+/// `when`에서는 적힌 조건을 모두 만족해야 실행되고,
+/// `skip`에서는 적힌 조건 중 하나라도 만족하면 건너뜁니다.
+/// 목록으로 적은 값은 그중 하나와 일치하면 만족합니다.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct Condition {
+    /// 현재 브랜치 이름 glob (`main`, `release/*`)
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub branch: Vec<String>,
+    /// 변경된 파일 glob (단계의 `glob`과 같은 규칙으로 비교, 변경 범위는 hook마다 다름)
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub changed: Vec<String>,
+    /// 저장소 루트 기준으로 있어야 하는 파일이나 디렉토리
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub exists: Vec<String>,
+    /// 설정되어 있어야 하는 환경 변수 (`NAME` 또는 `NAME=value`)
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub env: Vec<String>,
+    /// merge 진행 중인지 여부
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub merge: Option<bool>,
+    /// rebase 진행 중인지 여부
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rebase: Option<bool>,
+    /// 이 버전이 모르는 조건 (확인하지 않고 경고만 하며, 저장할 때 보존)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// 조건 하나를 확인한 결과
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// `--explain`에 표시할 설명
+    pub description: String,
+    pub holds: bool,
+}
+
+impl Check {
+    fn new(holds: bool, description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            holds,
+        }
+    }
+}
+
+impl Condition {
+    /// 적힌 조건을 하나씩 확인
+    pub async fn evaluate(&self, facts: &mut Facts<'_>) -> Result<Vec<Check>> {
+        let mut checks = Vec::new();
+
+        if !self.branch.is_empty() {
+            let patterns = self.branch.join(", ");
+            checks.push(match facts.branch().await? {
+                Some(branch) if matches_branch(&self.branch, branch)? => {
+                    Check::new(true, format!("branch {} matches {}", branch, patterns))
+                }
+                Some(branch) => Check::new(
+                    false,
+                    format!("branch {} does not match {}", branch, patterns),
+                ),
+                None => Check::new(false, "HEAD is detached"),
+            });
+        }
+
+        if !self.changed.is_empty() {
+            let patterns = self.changed.join(", ");
+            let matcher = FileMatcher::new(&self.changed)?;
+            let changed = matcher.filter(facts.changed_files().await?);
+            checks.push(match changed.as_slice() {
+                [] => Check::new(false, format!("no changed file matches {}", patterns)),
+                [file] => Check::new(true, format!("{} changed ({})", file, patterns)),
+                [file, rest @ ..] => Check::new(
+                    true,
+                    format!("{} and {} more changed ({})", file, rest.len(), patterns),
+                ),
+            });
+        }
+
+        for path in &self.exists {
+            checks.push(if std::path::Path::new(path).exists() {
+                Check::new(true, format!("{} exists", path))
+            } else {
+                Check::new(false, format!("{} does not exist", path))
+            });
+        }
+
+        for variable in &self.env {
+            checks.push(check_env(variable, |name| std::env::var(name).ok()));
+        }
+
+        if let Some(expected) = self.merge {
+            let merging = facts.merging().await?;
+            checks.push(Check::new(
+                merging == expected,
+                if merging {
+                    "merge in progress"
+                } else {
+                    "not a merge"
+                },
+            ));
+        }
+
+        if let Some(expected) = self.rebase {
+            let rebasing = facts.rebasing().await?;
+            checks.push(Check::new(
+                rebasing == expected,
+                if rebasing {
+                    "rebase in progress"
+                } else {
+                    "not a rebase"
+                },
+            ));
+        }
+
+        Ok(checks)
+    }
+}
+
+/// 조건 확인에 필요한 저장소 상태 (필요할 때 한 번만 조회)
+pub struct Facts<'a> {
+    context: &'a HookContext,
+    branch: Option<Option<String>>,
+    changed_files: Option<Vec<String>>,
+    merging: Option<bool>,
+    rebasing: Option<bool>,
+}
+
+impl<'a> Facts<'a> {
+    pub fn new(context: &'a HookContext) -> Self {
+        Self {
+            context,
+            branch: None,
+            changed_files: None,
+            merging: None,
+            rebasing: None,
+        }
+    }
+
+    pub async fn branch(&mut self) -> Result<Option<&str>> {
+        if self.branch.is_none() {
+            self.branch = Some(Git::get_current_branch().await?);
+        }
+        Ok(self.branch.as_ref().and_then(|branch| branch.as_deref()))
+    }
+
+    /// 이 hook에서 변경된 것으로 보는 파일 목록
+    pub async fn changed_files(&mut self) -> Result<&[String]> {
+        if self.changed_files.is_none() {
+            self.changed_files = Some(changed_files(self.context).await?);
+        }
+        Ok(self.changed_files.as_deref().unwrap_or_default())
+    }
+
+    pub async fn merging(&mut self) -> Result<bool> {
+        if self.merging.is_none() {
+            let merging = self.context.commit_source() == Some("merge")
+                || Git::get_git_path("MERGE_HEAD").await?.exists();
+            self.merging = Some(merging);
+        }
+        Ok(self.merging.unwrap_or_default())
+    }
+
+    pub async fn rebasing(&mut self) -> Result<bool> {
+        if self.rebasing.is_none() {
+            let rebasing = Git::get_git_path("rebase-merge").await?.exists()
+                || Git::get_git_path("rebase-apply").await?.exists();
+            self.rebasing = Some(rebasing);
+        }
+        Ok(self.rebasing.unwrap_or_default())
+    }
+}
+
+/// hook 종류에 따라 변경된 파일 계산
+///
+/// pre-push는 원격 ref와 push할 로컬 ref 사이, post-merge는 merge 전후 (`ORIG_HEAD`와 `HEAD`),
+/// post-checkout은 이전 HEAD와 새 HEAD 사이에서 바뀐 파일이고, 나머지 hook은 staged 파일입니다.
+async fn changed_files(context: &HookContext) -> Result<Vec<String>> {
+    match context.hook_name.as_str() {
+        "pre-push" => {
+            let mut files = Vec::new();
+            for pushed in context.pushed_refs() {
+                if pushed.is_delete() {
+                    continue;
+                }
+                if !pushed.is_new() && Git::commit_exists(&pushed.remote_sha).await? {
+                    files.extend(
+                        Git::get_changed_files(&pushed.remote_sha, &pushed.local_sha).await?,
+                    );
+                } else {
+                    // 새 브랜치이거나 원격 커밋을 fetch하지 않았으면 원격에 없는 커밋 기준
+                    files.extend(Git::get_unpushed_files(&pushed.local_sha).await?);
+                }
+            }
+            files.sort();
+            files.dedup();
+            Ok(files)
+        }
+        "post-merge" => {
+            if Git::commit_exists("ORIG_HEAD").await? {
+                Git::get_changed_files("ORIG_HEAD", "HEAD").await
+            } else {
+                Ok(Vec::new())
+            }
+        }
+        "post-checkout" => match context.checkout_heads() {
+            // clone 직후에는 이전 HEAD가 없으므로 모든 파일이 바뀐 것으로 봄
+            Some((previous, new)) if is_zero_sha(previous) => Git::get_tree_files(new).await,
+            Some((previous, new)) => Git::get_changed_files(previous, new).await,
+            None => Ok(Vec::new()),
+        },
+        _ => Git::get_staged_files().await,
+    }
+}
+
+/// 브랜치 이름을 glob과 비교 (`*`는 `/`를 넘지 않음)
+fn matches_branch(patterns: &[String], branch: &str) -> Result<bool> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .map_err(|e| anyhow!("Invalid branch pattern {}: {}", pattern, e))?;
+        builder.add(glob);
+    }
+    Ok(builder.build()?.is_match(branch))
+}
+
+fn check_env(variable: &str, var: impl Fn(&str) -> Option<String>) -> Check {
+    match variable.split_once('=') {
+        Some((name, expected)) => match var(name) {
+            Some(value) if value == expected => {
+                Check::new(true, format!("{} is {}", name, expected))
+            }
+            Some(value) => Check::new(false, format!("{} is {}, not {}", name, value, expected)),
+            None => Check::new(false, format!("{} is not set", name)),
+        },
+        None => match var(variable) {
+            Some(value) if !value.is_empty() => Check::new(true, format!("{} is set", variable)),
+            _ => Check::new(false, format!("{} is not set", variable)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+    use tokio::process::Command;
+
+    async fn git(args: &[&str]) {
+        Command::new("git").args(args).output().await.unwrap();
+    }
+
+    #[test]
+    fn test_parse_condition() {
+        let condition: Condition = serde_json::from_str(
+            r#"{ "branch": "main", "changed": ["src/**", "Cargo.toml"], "merge": false }"#,
+        )
+        .unwrap();
+        assert_eq!(condition.branch, vec!["main"]);
+        assert_eq!(condition.changed, vec!["src/**", "Cargo.toml"]);
+        assert_eq!(condition.merge, Some(false));
+        assert_eq!(condition.rebase, None);
+
+        // 모르는 조건은 설정을 읽지 못하게 하지 않고 그대로 보존
+        let condition: Condition =
+            serde_json::from_str(r#"{ "branch": "main", "branches": "main" }"#).unwrap();
+        assert_eq!(condition.branch, vec!["main"]);
+        assert_eq!(condition.extra["branches"], "main");
+        assert_eq!(
+            serde_json::to_string(&condition).unwrap(),
+            r#"{"branch":["main"],"branches":"main"}"#
+        );
+    }
+
+    #[test]
+    fn test_matches_branch() {
+        let patterns = vec!["main".to_string(), "release/*".to_string()];
+        assert!(matches_branch(&patterns, "main").unwrap());
+        assert!(matches_branch(&patterns, "release/1.2").unwrap());
+        assert!(!matches_branch(&patterns, "release/1.2/hotfix").unwrap());
+        assert!(!matches_branch(&patterns, "feature/main").unwrap());
+    }
+
+    #[test]
+    fn test_check_env() {
+        let var = |name: &str| match name {
+            "DEPLOY" => Some("prod".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        };
+        assert!(check_env("DEPLOY", var).holds);
+        assert!(check_env("DEPLOY=prod", var).holds);
+        assert_eq!(
+            check_env("DEPLOY=staging", var).description,
+            "DEPLOY is prod, not staging"
+        );
+        assert!(!check_env("EMPTY", var).holds);
+        assert!(!check_env("MISSING", var).holds);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_evaluate_against_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q", "-b", "main"]).await;
+
+        std::fs::create_dir("src").unwrap();
+        std::fs::write("src/lib.rs", "").unwrap();
+        git(&["add", "src/lib.rs"]).await;
+
+        let condition = Condition {
+            branch: vec!["main".to_string()],
+            changed: vec!["*.rs".to_string()],
+            exists: vec!["Cargo.toml".to_string()],
+            merge: Some(false),
+            rebase: Some(false),
+            ..Condition::default()
+        };
+        let context = HookContext::new("pre-commit", &[]);
+        let mut facts = Facts::new(&context);
+        let checks = condition.evaluate(&mut facts).await.unwrap();
+
+        let results: Vec<(bool, &str)> = checks
+            .iter()
+            .map(|check| (check.holds, check.description.as_str()))
+            .collect();
+        assert_eq!(
+            results,
+            vec![
+                (true, "branch main matches main"),
+                (true, "src/lib.rs changed (*.rs)"),
+                (false, "Cargo.toml does not exist"),
+                (true, "not a merge"),
+                (true, "not a rebase"),
+            ]
+        );
+
+        std::fs::write(
+            ".git/MERGE_HEAD",
+            "0000000000000000000000000000000000000000\n",
+        )
+        .unwrap();
+        let mut facts = Facts::new(&context);
+        assert!(facts.merging().await.unwrap());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    async fn commit(message: &str) -> String {
+        git(&["add", "-A"]).await;
+        git(&[
+            "-c",
+            "user.name=Test",
+            "-c",
+            "user.email=test@example.com",
+            "commit",
+            "-q",
+            "-m",
+            message,
+        ])
+        .await;
+        let output = Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .await
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap().trim().to_string()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_changed_files_per_hook() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        std::fs::write("README.md", "readme").unwrap();
+        let first = commit("first").await;
+        std::fs::create_dir("backend").unwrap();
+        std::fs::write("backend/api.rs", "fn main() {}").unwrap();
+        std::fs::write("package-lock.json", "{}").unwrap();
+        let second = commit("second").await;
+        let changed = vec!["backend/api.rs", "package-lock.json"];
+
+        // pre-push: 원격 ref와 로컬 ref 사이
+        let stdin = format!("refs/heads/main {} refs/heads/main {}\n", second, first);
+        let context = HookContext::new("pre-push", &["origin".to_string()]).with_stdin(stdin);
+        assert_eq!(changed_files(&context).await.unwrap(), changed);
+
+        // 새 브랜치는 원격에 없는 모든 커밋
+        let zero = "0".repeat(40);
+        let stdin = format!("refs/heads/topic {} refs/heads/topic {}\n", second, zero);
+        let context = HookContext::new("pre-push", &["origin".to_string()]).with_stdin(stdin);
+        assert_eq!(
+            changed_files(&context).await.unwrap(),
+            vec!["README.md", "backend/api.rs", "package-lock.json"]
+        );
+
+        // 삭제하는 push는 변경 없음
+        let stdin = format!("(delete) {} refs/heads/old {}\n", zero, second);
+        let context = HookContext::new("pre-push", &["origin".to_string()]).with_stdin(stdin);
+        assert!(changed_files(&context).await.unwrap().is_empty());
+
+        // post-checkout: 이전 HEAD와 새 HEAD 사이 (clone이면 전체)
+        let context = HookContext::new(
+            "post-checkout",
+            &[first.clone(), second.clone(), "1".to_string()],
+        );
+        assert_eq!(changed_files(&context).await.unwrap(), changed);
+        let context = HookContext::new(
+            "post-checkout",
+            &[zero.clone(), second.clone(), "1".to_string()],
+        );
+        assert_eq!(changed_files(&context).await.unwrap().len(), 3);
+
+        // post-merge: ORIG_HEAD와 HEAD 사이
+        let context = HookContext::new("post-merge", &["0".to_string()]);
+        assert!(changed_files(&context).await.unwrap().is_empty());
+        git(&["update-ref", "ORIG_HEAD", &first]).await;
+        assert_eq!(changed_files(&context).await.unwrap(), changed);
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -1,22 +1,322 @@
 use anyhow::{anyhow, Result};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
 use std::path::Path;
 use tokio::fs;
 
+use crate::cache::{deserialize_cache, serialize_cache, CacheConfig};
+use crate::checks::{deserialize_builtin, serialize_builtin, Builtin};
+use crate::conditions::Condition;
+
 const CONFIG_FILE: &str = ".rusky/config.json";
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Config {
-    pub hooks: HashMap<String, String>,
+    #[serde(deserialize_with = "deserialize_hooks")]
+    pub hooks: BTreeMap<String, HookConfig>,
     pub version: String,
+    /// `core.hooksPath`로 지정해 hook 파일을 설치할 디렉토리 (없으면 `.git/hooks`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hooks_path: Option<String>,
+    /// 이 버전이 모르는 필드 (새 버전에서 추가된 설정을 지우지 않도록 보존)
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// 하나의 hook에 설정된 명령어 목록 (정의된 순서대로 실행)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct HookConfig {
+    #[serde(deserialize_with = "deserialize_commands")]
+    pub commands: Vec<HookCommand>,
+    /// 단계를 동시에 실행할지 여부
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub parallel: bool,
+    /// 병렬 실행 시 동시에 실행할 최대 단계 수 (기본값: CPU 수)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_jobs: Option<usize>,
+    /// 단계 실행 동안 unstaged 변경을 숨기고, 단계가 수정한 staged 파일을 다시 추가
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub stash: bool,
+    /// `stash`와 함께 untracked 파일도 숨기기
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub stash_untracked: bool,
+    /// 설치 시 백업해 둔 원래 hook을 단계 전/후에 함께 실행
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chain: Option<ChainMode>,
+    /// CI 환경 (`CI`, `GITHUB_ACTIONS` 등)에서는 hook 전체를 건너뛰기
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub skip_in_ci: bool,
+    /// 단계마다 적용할 제한 시간 (초, 단계에 `timeout`이 있으면 그 값을 사용)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_timeout"
+    )]
+    pub timeout: Option<u64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// 원래 hook을 실행할 시점
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainMode {
+    Before,
+    After,
+    /// 이 버전이 모르는 값 (원래 hook은 실행하지 않고, 저장할 때는 그대로 보존)
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// hook에서 실행할 이름 있는 명령어 단계
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct HookCommand {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub run: String,
+    /// 셸 명령어 대신 실행할 rusky 내장 검사
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_builtin",
+        serialize_with = "serialize_builtin"
+    )]
+    pub builtin: Option<Builtin>,
+    /// staged 파일 중 이 glob과 일치하는 파일만 인자로 넘겨 실행 (lint-staged 방식)
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_one_or_many"
+    )]
+    pub glob: Vec<String>,
+    /// 제한 시간 (초), 넘기면 명령어의 process group을 종료
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_timeout"
+    )]
+    pub timeout: Option<u64>,
+    /// 모두 만족할 때만 단계 실행
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<Condition>,
+    /// 하나라도 만족하면 단계 건너뛰기
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip: Option<Condition>,
+    /// 같은 입력으로 성공한 적이 있으면 다시 실행하지 않음
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_cache",
+        serialize_with = "serialize_cache"
+    )]
+    pub cache: Option<CacheConfig>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// hook 설정의 여러 표기법 (`"npm test"`, `["fmt", "test"]`, `{ "commands": [...] }`)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HookEntry {
+    Command(String),
+    Commands(Vec<CommandEntry>),
+    Config(HookConfig),
+}
+
+/// 명령어 단계의 표기법 (`"npm test"` 또는 `{ "name": ..., "run": ... }`)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CommandEntry {
+    Run(String),
+    Command(Box<HookCommand>),
+}
+
+impl From<CommandEntry> for HookCommand {
+    fn from(entry: CommandEntry) -> Self {
+        match entry {
+            CommandEntry::Run(run) => HookCommand::new(String::new(), run),
+            CommandEntry::Command(command) => *command,
+        }
+    }
+}
+
+fn deserialize_hooks<'de, D>(deserializer: D) -> Result<BTreeMap<String, HookConfig>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let entries = BTreeMap::<String, HookEntry>::deserialize(deserializer)?;
+
+    Ok(entries
+        .into_iter()
+        .map(|(hook_name, entry)| {
+            let mut hook = match entry {
+                HookEntry::Command(run) => HookConfig {
+                    commands: vec![HookCommand::new(String::new(), run)],
+                    ..HookConfig::default()
+                },
+                HookEntry::Commands(commands) => HookConfig {
+                    commands: commands.into_iter().map(HookCommand::from).collect(),
+                    ..HookConfig::default()
+                },
+                HookEntry::Config(hook) => hook,
+            };
+            hook.assign_missing_names();
+            (hook_name, hook)
+        })
+        .collect())
+}
+
+fn deserialize_commands<'de, D>(deserializer: D) -> Result<Vec<HookCommand>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let entries = Vec::<CommandEntry>::deserialize(deserializer)?;
+    Ok(entries.into_iter().map(HookCommand::from).collect())
+}
+
+/// `"*.rs"`와 `["*.rs", "*.toml"]` 두 가지 표기를 모두 허용
+pub fn deserialize_one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+/// `90`, `"90s"`, `"5m"`, `"1m30s"` 같은 시간 표기를 초로 변환
+fn deserialize_timeout<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Timeout {
+        Seconds(u64),
+        Text(String),
+    }
+
+    match Option::<Timeout>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Timeout::Seconds(seconds)) => Ok(Some(seconds)),
+        Some(Timeout::Text(text)) => parse_timeout(&text)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+fn parse_timeout(text: &str) -> Result<u64> {
+    let invalid = || anyhow!("Invalid timeout {} (use e.g. 30, \"30s\", \"5m\" or \"1h\")", text);
+
+    let mut seconds = 0;
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return Err(invalid());
+    }
+    while !rest.is_empty() {
+        let split = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(split);
+        let number: u64 = number.parse().map_err(|_| invalid())?;
+
+        let unit_end = tail.find(|c: char| c.is_ascii_digit()).unwrap_or(tail.len());
+        let (unit, tail) = tail.split_at(unit_end);
+        let multiplier = match unit.trim() {
+            "" | "s" | "sec" => 1,
+            "m" | "min" => 60,
+            "h" => 60 * 60,
+            _ => return Err(invalid()),
+        };
+        seconds += number * multiplier;
+        rest = tail;
+    }
+
+    Ok(seconds)
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl HookCommand {
+    pub fn new(name: String, run: String) -> Self {
+        Self {
+            name,
+            run,
+            builtin: None,
+            glob: Vec::new(),
+            timeout: None,
+            when: None,
+            skip: None,
+            cache: None,
+            extra: Map::new(),
+        }
+    }
+}
+
+impl HookConfig {
+    /// 이름으로 명령어 단계 찾기
+    pub fn get_command(&self, name: &str) -> Option<&HookCommand> {
+        self.commands.iter().find(|command| command.name == name)
+    }
+
+    /// 이름으로 명령어 단계 찾기 (수정 가능)
+    pub fn get_command_mut(&mut self, name: &str) -> Option<&mut HookCommand> {
+        self.commands.iter_mut().find(|command| command.name == name)
+    }
+
+    /// 명령어에서 중복되지 않는 단계 이름 만들기 (예: `cargo fmt` -> `cargo`, `cargo-2`)
+    fn unique_name(&self, run: &str) -> String {
+        let base = run
+            .split_whitespace()
+            .next()
+            .and_then(|program| program.rsplit('/').next())
+            .filter(|program| !program.is_empty())
+            .unwrap_or("step")
+            .to_string();
+
+        if self.get_command(&base).is_none() {
+            return base;
+        }
+
+        (2..)
+            .map(|index| format!("{}-{}", base, index))
+            .find(|name| self.get_command(name).is_none())
+            .unwrap()
+    }
+
+    /// 이름이 없는 단계에 명령어 기반 이름 부여
+    fn assign_missing_names(&mut self) {
+        for index in 0..self.commands.len() {
+            if self.commands[index].name.is_empty() {
+                let command = &self.commands[index];
+                let source = match &command.builtin {
+                    Some(builtin) => builtin.name(),
+                    None => command.run.as_str(),
+                };
+                let name = self.unique_name(source);
+                self.commands[index].name = name;
+            }
+        }
+    }
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
-            hooks: HashMap::new(),
+            hooks: BTreeMap::new(),
             version: "0.1.0".to_string(),
+            hooks_path: None,
+            extra: Map::new(),
         }
     }
 }
@@ -31,6 +331,16 @@ impl Config {
         let config: Config = serde_json::from_str(&content)
             .map_err(|e| anyhow!("Failed to parse config file: {}", e))?;
 
+        for (hook_name, hook) in &config.hooks {
+            if let Some(step) = hook.commands.iter().find(|step| step.run.is_empty() && step.builtin.is_none()) {
+                return Err(anyhow!(
+                    "Step {} of the {} hook needs either 'run' or 'builtin'",
+                    step.name,
+                    hook_name
+                ));
+            }
+        }
+
         Ok(config)
     }
 
@@ -41,23 +351,83 @@ impl Config {
             fs::create_dir(rusky_dir).await?;
         }
 
-        let content = serde_json::to_string_pretty(self)
+        let mut content = serde_json::to_string_pretty(self)
             .map_err(|e| anyhow!("Failed to serialize config: {}", e))?;
+        content.push('\n');
 
         fs::write(CONFIG_FILE, content).await?;
         Ok(())
     }
 
-    pub fn add_hook(&mut self, hook_name: String, command: String) {
-        self.hooks.insert(hook_name, command);
+    /// hook에 명령어 단계 추가 (이름은 명령어에서 자동 생성) 후 단계 이름 반환
+    pub fn add_hook(&mut self, hook_name: String, command: String) -> String {
+        let hook = self.hooks.entry(hook_name).or_default();
+        if let Some(existing) = hook.commands.iter().find(|step| step.run == command) {
+            return existing.name.clone();
+        }
+
+        let name = hook.unique_name(&command);
+        hook.commands.push(HookCommand::new(name.clone(), command));
+        name
+    }
+
+    /// 이름을 지정해 명령어 단계 추가, 같은 이름이 있으면 교체하고 true 반환
+    pub fn add_step(&mut self, hook_name: String, name: String, command: String) -> bool {
+        let hook = self.hooks.entry(hook_name).or_default();
+        match hook.commands.iter_mut().find(|step| step.name == name) {
+            Some(step) => {
+                step.run = command;
+                true
+            }
+            None => {
+                hook.commands.push(HookCommand::new(name, command));
+                false
+            }
+        }
+    }
+
+    /// 완성된 단계 추가 (같은 단계가 이미 있으면 추가하지 않고 false 반환)
+    ///
+    /// 이름이 없거나 이미 쓰이는 이름이면 중복되지 않는 이름으로 바꿉니다.
+    pub fn add_command(&mut self, hook_name: &str, mut step: HookCommand) -> bool {
+        let hook = self.hooks.entry(hook_name.to_string()).or_default();
+        let exists = hook.commands.iter().any(|existing| {
+            existing.run == step.run && existing.builtin == step.builtin && existing.glob == step.glob
+        });
+        if exists {
+            return false;
+        }
+
+        if step.name.is_empty() || hook.get_command(&step.name).is_some() {
+            let source = if step.name.is_empty() { step.run.clone() } else { step.name.clone() };
+            step.name = hook.unique_name(&source);
+        }
+        hook.commands.push(step);
+        true
     }
 
     pub fn remove_hook(&mut self, hook_name: &str) -> bool {
         self.hooks.remove(hook_name).is_some()
     }
 
-    #[allow(dead_code)]
-    pub fn get_hook(&self, hook_name: &str) -> Option<&String> {
+    /// hook에서 명령어 단계 하나 제거 (마지막 단계였다면 hook도 제거)
+    pub fn remove_step(&mut self, hook_name: &str, name: &str) -> bool {
+        let Some(hook) = self.hooks.get_mut(hook_name) else {
+            return false;
+        };
+
+        let before = hook.commands.len();
+        hook.commands.retain(|step| step.name != name);
+        let removed = hook.commands.len() != before;
+
+        if hook.commands.is_empty() {
+            self.hooks.remove(hook_name);
+        }
+
+        removed
+    }
+
+    pub fn get_hook(&self, hook_name: &str) -> Option<&HookConfig> {
         self.hooks.get(hook_name)
     }
 
@@ -70,9 +440,16 @@ impl Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
     use tempfile::TempDir;
     use std::env;
 
+    fn runs<'a>(config: &'a Config, hook_name: &str) -> Vec<&'a str> {
+        config.get_hook(hook_name)
+            .map(|hook| hook.commands.iter().map(|step| step.run.as_str()).collect())
+            .unwrap_or_default()
+    }
+
     #[tokio::test]
     async fn test_config_default() {
         let config = Config::default();
@@ -86,7 +463,94 @@ mod tests {
         config.add_hook("pre-commit".to_string(), "echo test".to_string());
         
         assert_eq!(config.hooks.len(), 1);
-        assert_eq!(config.hooks.get("pre-commit"), Some(&"echo test".to_string()));
+        assert_eq!(runs(&config, "pre-commit"), vec!["echo test"]);
+    }
+
+    #[tokio::test]
+    async fn test_add_hook_appends_steps() {
+        let mut config = Config::default();
+        let first = config.add_hook("pre-commit".to_string(), "cargo fmt --check".to_string());
+        let second = config.add_hook("pre-commit".to_string(), "cargo clippy".to_string());
+        let duplicate = config.add_hook("pre-commit".to_string(), "cargo fmt --check".to_string());
+
+        assert_eq!(first, "cargo");
+        assert_eq!(second, "cargo-2");
+        assert_eq!(duplicate, "cargo");
+        assert_eq!(runs(&config, "pre-commit"), vec!["cargo fmt --check", "cargo clippy"]);
+    }
+
+    #[tokio::test]
+    async fn test_add_step_replaces_by_name() {
+        let mut config = Config::default();
+        let replaced = config.add_step("pre-commit".to_string(), "fmt".to_string(), "cargo fmt".to_string());
+        assert!(!replaced);
+        config.add_step("pre-commit".to_string(), "test".to_string(), "cargo test".to_string());
+        let replaced = config.add_step("pre-commit".to_string(), "fmt".to_string(), "cargo fmt --check".to_string());
+        assert!(replaced);
+
+        assert_eq!(runs(&config, "pre-commit"), vec!["cargo fmt --check", "cargo test"]);
+    }
+
+    #[test]
+    fn test_add_command_skips_duplicates_and_renames() {
+        let mut config = Config::default();
+        let mut lint = HookCommand::new("lint".to_string(), "npx eslint".to_string());
+        lint.glob = vec!["*.js".to_string()];
+
+        assert!(config.add_command("pre-commit", lint.clone()));
+        assert!(!config.add_command("pre-commit", lint.clone()));
+
+        lint.glob = vec!["*.ts".to_string()];
+        assert!(config.add_command("pre-commit", lint));
+        assert!(config.add_command("pre-commit", HookCommand::new(String::new(), "npm test".to_string())));
+
+        let names: Vec<&str> = config.get_hook("pre-commit").unwrap().commands.iter()
+            .map(|step| step.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["lint", "lint-2", "npm"]);
+    }
+
+    #[tokio::test]
+    async fn test_remove_step() {
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "fmt".to_string(), "cargo fmt".to_string());
+        config.add_step("pre-commit".to_string(), "test".to_string(), "cargo test".to_string());
+
+        assert!(config.remove_step("pre-commit", "fmt"));
+        assert!(!config.remove_step("pre-commit", "fmt"));
+        assert_eq!(runs(&config, "pre-commit"), vec!["cargo test"]);
+
+        // 마지막 단계를 제거하면 hook도 제거됨
+        assert!(config.remove_step("pre-commit", "test"));
+        assert!(config.get_hook("pre-commit").is_none());
+    }
+
+    #[test]
+    fn test_parse_legacy_and_step_formats() {
+        let json = r#"{
+            "hooks": {
+                "pre-push": "npm test",
+                "commit-msg": ["npx commitlint --edit $1"],
+                "pre-commit": {
+                    "commands": [
+                        { "name": "fmt", "run": "cargo fmt --check" },
+                        "cargo clippy",
+                        { "run": "cargo test --lib" }
+                    ]
+                }
+            },
+            "version": "0.1.0"
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        assert_eq!(runs(&config, "pre-push"), vec!["npm test"]);
+        assert_eq!(config.get_hook("pre-push").unwrap().commands[0].name, "npm");
+        assert_eq!(runs(&config, "commit-msg"), vec!["npx commitlint --edit $1"]);
+
+        let names: Vec<&str> = config.get_hook("pre-commit").unwrap().commands.iter()
+            .map(|step| step.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["fmt", "cargo", "cargo-2"]);
     }
 
     #[tokio::test]
@@ -107,7 +571,7 @@ mod tests {
         let mut config = Config::default();
         config.add_hook("pre-commit".to_string(), "echo test".to_string());
         
-        assert_eq!(config.get_hook("pre-commit"), Some(&"echo test".to_string()));
+        assert_eq!(config.get_hook("pre-commit").unwrap().commands[0].run, "echo test");
         assert_eq!(config.get_hook("non-existent"), None);
     }
 
@@ -121,6 +585,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_save_and_load_config() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -143,15 +608,209 @@ mod tests {
         // 설정 로드 및 검증
         let loaded_config = Config::load().await.unwrap();
         assert_eq!(loaded_config.hooks.len(), 2);
-        assert_eq!(loaded_config.hooks.get("pre-commit"), Some(&"echo test".to_string()));
-        assert_eq!(loaded_config.hooks.get("pre-push"), Some(&"npm test".to_string()));
+        assert_eq!(runs(&loaded_config, "pre-commit"), vec!["echo test"]);
+        assert_eq!(runs(&loaded_config, "pre-push"), vec!["npm test"]);
         assert_eq!(loaded_config.version, "0.1.0");
         
         // 원래 디렉토리로 복원
         env::set_current_dir(original_dir).unwrap();
     }
 
+    #[test]
+    fn test_parse_parallel_options() {
+        let json = r#"{
+            "hooks": {
+                "pre-commit": { "commands": ["eslint .", "stylelint ."], "parallel": true, "max_jobs": 2 },
+                "pre-push": { "commands": ["npm test"] }
+            },
+            "version": "0.1.0"
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        let pre_commit = config.get_hook("pre-commit").unwrap();
+        assert!(pre_commit.parallel);
+        assert_eq!(pre_commit.max_jobs, Some(2));
+
+        let pre_push = config.get_hook("pre-push").unwrap();
+        assert!(!pre_push.parallel);
+        assert_eq!(pre_push.max_jobs, None);
+
+        // 기본값은 저장하지 않음
+        let value = serde_json::to_value(&config).unwrap();
+        assert!(value["hooks"]["pre-push"].get("parallel").is_none());
+        assert!(value["hooks"]["pre-push"].get("max_jobs").is_none());
+    }
+
+    #[test]
+    fn test_parse_timeouts() {
+        let json = r#"{
+            "hooks": {
+                "pre-push": {
+                    "commands": [
+                        { "name": "test", "run": "npm test", "timeout": "1m30s" },
+                        { "name": "lint", "run": "npm run lint" }
+                    ],
+                    "timeout": 300
+                }
+            },
+            "version": "0.1.0"
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        let pre_push = config.get_hook("pre-push").unwrap();
+        assert_eq!(pre_push.timeout, Some(300));
+        assert_eq!(pre_push.commands[0].timeout, Some(90));
+        assert_eq!(pre_push.commands[1].timeout, None);
+
+        assert_eq!(parse_timeout("45").unwrap(), 45);
+        assert_eq!(parse_timeout("2h").unwrap(), 7200);
+        assert_eq!(parse_timeout("10 min").unwrap(), 600);
+        assert!(parse_timeout("soon").is_err());
+        assert!(parse_timeout("").is_err());
+    }
+
+    #[test]
+    fn test_parse_glob_steps() {
+        let json = r#"{
+            "hooks": {
+                "pre-commit": {
+                    "commands": [
+                        { "name": "rustfmt", "run": "rustfmt --check", "glob": "*.rs" },
+                        { "name": "eslint", "run": "eslint", "glob": ["*.js", "*.ts"] },
+                        { "name": "test", "run": "cargo test" }
+                    ]
+                }
+            },
+            "version": "0.1.0"
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let hook = config.get_hook("pre-commit").unwrap();
+
+        assert_eq!(hook.get_command("rustfmt").unwrap().glob, vec!["*.rs"]);
+        assert_eq!(hook.get_command("eslint").unwrap().glob, vec!["*.js", "*.ts"]);
+        assert!(hook.get_command("test").unwrap().glob.is_empty());
+
+        let value = serde_json::to_value(&config).unwrap();
+        assert!(value["hooks"]["pre-commit"]["commands"][2].get("glob").is_none());
+    }
+
+    #[test]
+    fn test_parse_builtin_steps() {
+        let json = r#"{
+            "hooks": {
+                "commit-msg": {
+                    "commands": [
+                        { "builtin": "conventional-commits" },
+                        { "name": "strict", "builtin": { "type": "conventional-commits", "require_scope": true } }
+                    ]
+                }
+            },
+            "version": "0.1.0"
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let hook = config.get_hook("commit-msg").unwrap();
+
+        // 이름이 없으면 검사 이름을 사용
+        let default = hook.get_command("conventional-commits").unwrap();
+        assert!(default.run.is_empty());
+        assert_eq!(default.builtin.as_ref().unwrap().name(), "conventional-commits");
+
+        // 기본 설정은 이름만 저장하고, 바꾼 설정은 그대로 저장
+        let value = serde_json::to_value(&config).unwrap();
+        let commands = &value["hooks"]["commit-msg"]["commands"];
+        assert_eq!(commands[0]["builtin"], "conventional-commits");
+        assert!(commands[0].get("run").is_none());
+        assert_eq!(commands[1]["builtin"]["type"], "conventional-commits");
+        assert_eq!(commands[1]["builtin"]["require_scope"], true);
+        assert_eq!(serde_json::from_value::<Config>(value).unwrap(), config);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_load_rejects_step_without_run_or_builtin() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        fs::create_dir(".rusky").await.unwrap();
+        fs::write(
+            CONFIG_FILE,
+            r#"{ "hooks": { "pre-commit": [{ "name": "empty" }] }, "version": "0.1.0" }"#,
+        )
+        .await
+        .unwrap();
+        let error = Config::load().await.unwrap_err();
+        assert!(error.to_string().contains("Step empty of the pre-commit hook"));
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[test]
+    fn test_parse_chain_mode() {
+        let json = r#"{
+            "hooks": {
+                "pre-commit": { "commands": ["cargo fmt"], "chain": "before" },
+                "pre-push": { "commands": ["cargo test"], "chain": "after" },
+                "commit-msg": "commitlint"
+            },
+            "version": "0.1.0"
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.get_hook("pre-commit").unwrap().chain, Some(ChainMode::Before));
+        assert_eq!(config.get_hook("pre-push").unwrap().chain, Some(ChainMode::After));
+        assert_eq!(config.get_hook("commit-msg").unwrap().chain, None);
+
+        // 모르는 값 때문에 설정 전체를 읽지 못하면 안 됨
+        let unknown = r#"{ "hooks": { "pre-commit": { "commands": [], "chain": "never" } }, "version": "0.1.0" }"#;
+        let config: Config = serde_json::from_str(unknown).unwrap();
+        assert_eq!(
+            config.get_hook("pre-commit").unwrap().chain,
+            Some(ChainMode::Unknown(Value::from("never")))
+        );
+        assert!(serde_json::to_string(&config).unwrap().contains(r#""chain":"never""#));
+    }
+
+    #[test]
+    fn test_serialization_is_sorted() {
+        let mut config = Config::default();
+        config.add_hook("pre-push".to_string(), "npm test".to_string());
+        config.add_hook("commit-msg".to_string(), "commitlint".to_string());
+        config.add_hook("pre-commit".to_string(), "cargo fmt".to_string());
+
+        let json = serde_json::to_string_pretty(&config).unwrap();
+        let commit_msg = json.find("commit-msg").unwrap();
+        let pre_commit = json.find("pre-commit").unwrap();
+        let pre_push = json.find("pre-push").unwrap();
+        assert!(commit_msg < pre_commit && pre_commit < pre_push);
+
+        // 같은 설정은 항상 같은 문자열로 직렬화됨
+        let reparsed: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string_pretty(&reparsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_unknown_fields_are_preserved() {
+        let json = r#"{
+            "hooks": {
+                "pre-commit": {
+                    "commands": [{ "name": "fmt", "run": "cargo fmt", "future_step_option": 1 }],
+                    "future_hook_option": true
+                }
+            },
+            "version": "0.1.0",
+            "future_top_level": { "enabled": true }
+        }"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        let value = serde_json::to_value(&config).unwrap();
+
+        assert_eq!(value["future_top_level"]["enabled"], true);
+        assert_eq!(value["hooks"]["pre-commit"]["future_hook_option"], true);
+        assert_eq!(value["hooks"]["pre-commit"]["commands"][0]["future_step_option"], 1);
+    }
+
     #[tokio::test]
+    #[serial]
     async fn test_load_nonexistent_config() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
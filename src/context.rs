@@ -0,0 +1,306 @@
+use anyhow::Result;
+use std::io::IsTerminal;
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+use crate::hooks::shell_quote;
+
+/// git이 표준 입력으로 데이터를 넘겨주는 hook
+const STDIN_HOOKS: &[&str] = &[
+    "pre-push",
+    "pre-receive",
+    "post-receive",
+    "post-rewrite",
+    "reference-transaction",
+];
+
+/// git이 hook에 넘겨준 인자와 표준 입력
+#[derive(Debug, Clone, Default)]
+pub struct HookContext {
+    pub hook_name: String,
+    pub args: Vec<String>,
+    /// 표준 입력을 받지 않는 hook이면 None
+    pub stdin: Option<Vec<u8>>,
+}
+
+/// `pre-push`로 push되는 ref 한 줄
+#[derive(Debug, Clone, PartialEq)]
+pub struct PushedRef {
+    pub local_ref: String,
+    pub local_sha: String,
+    pub remote_ref: String,
+    pub remote_sha: String,
+}
+
+impl PushedRef {
+    /// 원격 ref를 삭제하는 push인지 확인
+    pub fn is_delete(&self) -> bool {
+        is_zero_sha(&self.local_sha)
+    }
+
+    /// 원격에 새로 만드는 ref인지 확인
+    pub fn is_new(&self) -> bool {
+        is_zero_sha(&self.remote_sha)
+    }
+}
+
+impl HookContext {
+    pub fn new(hook_name: &str, args: &[String]) -> Self {
+        Self {
+            hook_name: hook_name.to_string(),
+            args: args.to_vec(),
+            stdin: None,
+        }
+    }
+
+    pub fn with_stdin(mut self, stdin: impl Into<Vec<u8>>) -> Self {
+        self.stdin = Some(stdin.into());
+        self
+    }
+
+    /// 현재 프로세스의 표준 입력을 읽어 context 만들기
+    ///
+    /// 표준 입력을 받는 hook이고 터미널에서 직접 실행한 경우가 아닐 때만 읽습니다.
+    pub async fn read(hook_name: &str, args: &[String]) -> Result<Self> {
+        let context = Self::new(hook_name, args);
+        if !STDIN_HOOKS.contains(&hook_name) || std::io::stdin().is_terminal() {
+            return Ok(context);
+        }
+
+        let mut stdin = Vec::new();
+        tokio::io::stdin().read_to_end(&mut stdin).await?;
+        Ok(context.with_stdin(stdin))
+    }
+
+    fn arg(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// `commit-msg`, `prepare-commit-msg`, `applypatch-msg`의 메시지 파일
+    pub fn message_file(&self) -> Option<&Path> {
+        match self.hook_name.as_str() {
+            "commit-msg" | "prepare-commit-msg" | "applypatch-msg" => self.arg(0).map(Path::new),
+            _ => None,
+        }
+    }
+
+    /// `prepare-commit-msg`의 메시지 출처 (message, template, merge, squash, commit)
+    pub fn commit_source(&self) -> Option<&str> {
+        match self.hook_name.as_str() {
+            "prepare-commit-msg" => self.arg(1),
+            _ => None,
+        }
+    }
+
+    /// `pre-push`의 원격 이름
+    pub fn remote(&self) -> Option<&str> {
+        match self.hook_name.as_str() {
+            "pre-push" => self.arg(0),
+            _ => None,
+        }
+    }
+
+    /// `pre-push`의 원격 URL
+    pub fn remote_url(&self) -> Option<&str> {
+        match self.hook_name.as_str() {
+            "pre-push" => self.arg(1),
+            _ => None,
+        }
+    }
+
+    /// `post-checkout`의 이전 HEAD와 새 HEAD
+    pub fn checkout_heads(&self) -> Option<(&str, &str)> {
+        match self.hook_name.as_str() {
+            "post-checkout" => Some((self.arg(0)?, self.arg(1)?)),
+            _ => None,
+        }
+    }
+
+    /// `pre-push`가 표준 입력으로 받은 ref 목록
+    pub fn pushed_refs(&self) -> Vec<PushedRef> {
+        if self.hook_name != "pre-push" {
+            return Vec::new();
+        }
+
+        self.stdin_lines()
+            .iter()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                Some(PushedRef {
+                    local_ref: fields.next()?.to_string(),
+                    local_sha: fields.next()?.to_string(),
+                    remote_ref: fields.next()?.to_string(),
+                    remote_sha: fields.next()?.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn stdin_lines(&self) -> Vec<String> {
+        self.stdin
+            .as_deref()
+            .map(|stdin| {
+                String::from_utf8_lossy(stdin)
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// hook 종류별로 명령어에서 사용할 수 있는 이름 있는 값
+    pub fn placeholders(&self) -> Vec<(&'static str, String)> {
+        let arg = |index: usize| self.arg(index).unwrap_or_default().to_string();
+
+        let mut placeholders = match self.hook_name.as_str() {
+            "applypatch-msg" | "commit-msg" => vec![("msg_file", arg(0))],
+            "prepare-commit-msg" => vec![("msg_file", arg(0)), ("source", arg(1)), ("sha", arg(2))],
+            "pre-push" => {
+                // 삭제하는 ref는 local ref가 `(delete)`라서 검사할 대상이 없음
+                let refs = self
+                    .pushed_refs()
+                    .iter()
+                    .filter(|pushed| !pushed.is_delete())
+                    .map(|pushed| pushed.local_ref.clone())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let remote = self.remote().unwrap_or_default().to_string();
+                let url = self.remote_url().unwrap_or_default().to_string();
+                vec![("remote", remote), ("url", url), ("refs", refs)]
+            }
+            "pre-rebase" => vec![("upstream", arg(0)), ("branch", arg(1))],
+            "post-checkout" => vec![
+                ("prev_head", arg(0)),
+                ("new_head", arg(1)),
+                ("branch_checkout", arg(2)),
+            ],
+            "post-merge" => vec![("squash", arg(0))],
+            "post-rewrite" => vec![("command", arg(0))],
+            "push-to-checkout" => vec![("sha", arg(0))],
+            _ => Vec::new(),
+        };
+
+        placeholders.push(("hook", self.hook_name.clone()));
+        placeholders
+    }
+
+    /// 명령어의 `{name}` 자리에 셸에 안전하게 감싼 값 넣기
+    ///
+    /// 알 수 없는 이름과 셸 변수(`${name}`)는 그대로 둡니다.
+    pub fn expand(&self, command: &str) -> String {
+        let placeholders = self.placeholders();
+        let mut expanded = String::with_capacity(command.len());
+        let mut rest = command;
+
+        while let Some(start) = rest.find('{') {
+            let (before, after) = rest.split_at(start);
+            expanded.push_str(before);
+
+            let is_shell_variable = before.ends_with('$');
+            let value = after[1..].find('}').and_then(|end| {
+                let name = &after[1..end + 1];
+                placeholders
+                    .iter()
+                    .find(|(placeholder, _)| *placeholder == name)
+                    .map(|(_, value)| (end + 2, value))
+            });
+
+            match value {
+                Some((length, value)) if !is_shell_variable => {
+                    expanded.push_str(&shell_quote(value));
+                    rest = &after[length..];
+                }
+                _ => {
+                    expanded.push('{');
+                    rest = &after[1..];
+                }
+            }
+        }
+
+        expanded.push_str(rest);
+        expanded
+    }
+}
+
+/// 삭제되거나 새로 만들어진 ref를 나타내는 object 이름 (모두 0)
+pub fn is_zero_sha(sha: &str) -> bool {
+    !sha.is_empty() && sha.bytes().all(|byte| byte == b'0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZERO_SHA: &str = "0000000000000000000000000000000000000000";
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_commit_msg_values() {
+        let context = HookContext::new("commit-msg", &args(&[".git/COMMIT_EDITMSG"]));
+        assert_eq!(context.message_file(), Some(Path::new(".git/COMMIT_EDITMSG")));
+        assert_eq!(context.commit_source(), None);
+        assert_eq!(
+            context.expand("commitlint --edit {msg_file}"),
+            "commitlint --edit '.git/COMMIT_EDITMSG'"
+        );
+
+        let context = HookContext::new("prepare-commit-msg", &args(&["MSG", "merge"]));
+        assert_eq!(context.commit_source(), Some("merge"));
+        assert_eq!(context.expand("echo {source} {sha}"), "echo 'merge' ''");
+    }
+
+    #[test]
+    fn test_pre_push_values() {
+        let stdin = format!(
+            "refs/heads/main 1111111111111111111111111111111111111111 refs/heads/main {}\n\
+             (delete) {} refs/heads/old 2222222222222222222222222222222222222222\n",
+            ZERO_SHA, ZERO_SHA
+        );
+        let context = HookContext::new("pre-push", &args(&["origin", "git@example.com:repo.git"]))
+            .with_stdin(stdin);
+
+        assert_eq!(context.remote(), Some("origin"));
+        assert_eq!(context.remote_url(), Some("git@example.com:repo.git"));
+
+        let refs = context.pushed_refs();
+        assert_eq!(refs.len(), 2);
+        assert_eq!(refs[0].local_ref, "refs/heads/main");
+        assert!(refs[0].is_new());
+        assert!(!refs[0].is_delete());
+        assert_eq!(refs[1].remote_ref, "refs/heads/old");
+        assert!(refs[1].is_delete());
+
+        assert_eq!(
+            context.expand("check {remote} {refs}"),
+            "check 'origin' 'refs/heads/main'"
+        );
+        assert_eq!(
+            context.expand("push {url}"),
+            "push 'git@example.com:repo.git'"
+        );
+    }
+
+    #[test]
+    fn test_expand_leaves_unknown_and_shell_syntax() {
+        let context = HookContext::new("commit-msg", &args(&["it's"]));
+        assert_eq!(context.expand("cat {msg_file}"), "cat 'it'\\''s'");
+        assert_eq!(context.expand("echo ${msg_file} {unknown} {"), "echo ${msg_file} {unknown} {");
+        assert_eq!(context.expand("echo {hook}"), "echo 'commit-msg'");
+        assert_eq!(
+            context.expand("for f in a b; do { echo $f; }; done"),
+            "for f in a b; do { echo $f; }; done"
+        );
+    }
+
+    #[test]
+    fn test_no_placeholders_for_other_hooks() {
+        let context = HookContext::new("pre-commit", &[]);
+        assert_eq!(context.message_file(), None);
+        assert!(context.pushed_refs().is_empty());
+        assert_eq!(context.expand("echo {msg_file}"), "echo {msg_file}");
+    }
+}
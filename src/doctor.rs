@@ -0,0 +1,418 @@
+use anyhow::Result;
+use colored::*;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use tokio::fs;
+
+use crate::config::Config;
+use crate::git::Git;
+use crate::hooks::{same_hooks_path, HookManager};
+
+/// 설치 상태에서 발견된 문제
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problem {
+    /// 설정된 hook 파일이 없음
+    Missing,
+    /// 다른 도구나 사람이 만든 hook 파일
+    NotManaged,
+    /// 실행 권한이 없음
+    NotExecutable,
+    /// 현재 rusky가 생성하는 내용과 다름
+    Outdated,
+    /// hook 환경에서 rusky 바이너리를 찾을 수 없음
+    BinaryNotFound,
+    /// 설정에 없는 hook을 rusky 파일이 처리하고 있음
+    NotConfigured,
+    /// `core.hooksPath`가 설정의 `hooks_path`와 다름
+    HooksPathMismatch {
+        expected: String,
+        actual: Option<String>,
+    },
+}
+
+impl Problem {
+    pub fn describe(&self) -> String {
+        match self {
+            Problem::Missing => "hook file is missing".to_string(),
+            Problem::NotManaged => "hook file was not created by rusky".to_string(),
+            Problem::NotExecutable => "hook file is not executable".to_string(),
+            Problem::Outdated => "hook file is outdated".to_string(),
+            Problem::BinaryNotFound => {
+                "rusky binary is not reachable from the hook (not on PATH or in node_modules/.bin)"
+                    .to_string()
+            }
+            Problem::NotConfigured => "hook is not configured in .rusky/config.json".to_string(),
+            Problem::HooksPathMismatch { expected, actual } => match actual {
+                Some(actual) => format!(
+                    "core.hooksPath is {} but rusky is configured for {}",
+                    actual, expected
+                ),
+                None => format!("core.hooksPath is not set to {}", expected),
+            },
+        }
+    }
+}
+
+/// 하나의 hook (또는 `core.hooksPath`)에 대한 진단 결과
+#[derive(Debug)]
+pub struct Diagnosis {
+    pub name: String,
+    pub problems: Vec<Problem>,
+}
+
+impl Diagnosis {
+    pub fn is_healthy(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+pub struct Doctor;
+
+impl Doctor {
+    /// 설정과 실제 설치 상태를 비교해 문제 목록 만들기
+    pub async fn diagnose(config: &Config) -> Result<Vec<Diagnosis>> {
+        let mut diagnoses = Vec::new();
+
+        if let Some(expected) = &config.hooks_path {
+            let actual = Git::get_config("core.hooksPath").await?;
+            let matches = actual
+                .as_deref()
+                .is_some_and(|actual| same_hooks_path(actual, expected));
+            diagnoses.push(Diagnosis {
+                name: "core.hooksPath".to_string(),
+                problems: if matches {
+                    Vec::new()
+                } else {
+                    vec![Problem::HooksPathMismatch {
+                        expected: expected.clone(),
+                        actual,
+                    }]
+                },
+            });
+        }
+
+        for hook_name in config.hooks.keys() {
+            diagnoses.push(Diagnosis {
+                name: hook_name.clone(),
+                problems: Self::check_hook(hook_name).await?,
+            });
+        }
+
+        // 설정에서 빠졌지만 남아 있는 rusky hook 파일
+        let hooks_dir = Git::get_hooks_dir().await?;
+        if hooks_dir.is_dir() {
+            let mut entries = fs::read_dir(&hooks_dir).await?;
+            let mut orphans = Vec::new();
+            while let Some(entry) = entries.next_entry().await? {
+                let hook_name = entry.file_name().to_string_lossy().into_owned();
+                if !config.hooks.contains_key(&hook_name)
+                    && HookManager::is_rusky_hook(&hook_name).await?
+                {
+                    orphans.push(hook_name);
+                }
+            }
+            orphans.sort();
+
+            diagnoses.extend(orphans.into_iter().map(|name| Diagnosis {
+                name,
+                problems: vec![Problem::NotConfigured],
+            }));
+        }
+
+        Ok(diagnoses)
+    }
+
+    /// 설정된 hook 파일 하나 검사
+    async fn check_hook(hook_name: &str) -> Result<Vec<Problem>> {
+        let hook_path = Git::get_hook_path(hook_name).await?;
+        if !hook_path.is_file() {
+            return Ok(vec![Problem::Missing]);
+        }
+
+        if !HookManager::is_rusky_hook(hook_name).await? {
+            return Ok(vec![Problem::NotManaged]);
+        }
+
+        let mut problems = Vec::new();
+
+        let mode = fs::metadata(&hook_path).await?.permissions().mode();
+        if mode & 0o111 == 0 {
+            problems.push(Problem::NotExecutable);
+        }
+
+        // 기록된 바이너리 경로는 설치 방법 (npx, cargo install 등)마다 다르므로 비교하지 않음
+        let content = String::from_utf8_lossy(&fs::read(&hook_path).await?).into_owned();
+        let expected = HookManager::installed_rusky_bin(&content)
+            .map(|rusky_bin| HookManager::hook_script(hook_name, &rusky_bin));
+        if expected.as_deref() != Some(content.as_str()) {
+            problems.push(Problem::Outdated);
+        }
+
+        if !Self::rusky_reachable(&content) {
+            problems.push(Problem::BinaryNotFound);
+        }
+
+        Ok(problems)
+    }
+
+    /// hook 스크립트가 rusky 바이너리를 찾을 수 있는지 확인 (스크립트의 탐색 순서와 동일)
+    fn rusky_reachable(script: &str) -> bool {
+        if std::env::var_os("RUSKY_BIN").is_some() {
+            return true;
+        }
+
+        if HookManager::installed_rusky_bin(script)
+            .is_some_and(|bin| is_executable(Path::new(&bin)))
+        {
+            return true;
+        }
+
+        let on_path = std::env::var_os("PATH").is_some_and(|path| {
+            std::env::split_paths(&path).any(|dir| is_executable(&dir.join("rusky")))
+        });
+
+        on_path || is_executable(Path::new("node_modules/.bin/rusky"))
+    }
+
+    /// 발견된 문제 고치기
+    pub async fn fix(config: &Config, diagnoses: &[Diagnosis]) -> Result<()> {
+        for diagnosis in diagnoses.iter().filter(|diagnosis| !diagnosis.is_healthy()) {
+            for problem in &diagnosis.problems {
+                match problem {
+                    Problem::HooksPathMismatch { .. } => {
+                        HookManager::prepare_hooks_dir(config, false).await?;
+                    }
+                    Problem::NotConfigured => {
+                        HookManager::remove_hook_file(&diagnosis.name).await?;
+                    }
+                    // 바이너리 위치는 shim을 다시 생성하는 것 외에는 고칠 수 없음
+                    Problem::BinaryNotFound => {}
+                    Problem::Missing
+                    | Problem::NotManaged
+                    | Problem::NotExecutable
+                    | Problem::Outdated => {
+                        HookManager::create_hook_file(&diagnosis.name).await?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 진단 결과를 표로 출력
+    pub fn print_report(diagnoses: &[Diagnosis]) {
+        let width = diagnoses
+            .iter()
+            .map(|diagnosis| diagnosis.name.len())
+            .max()
+            .unwrap_or(0)
+            .max("HOOK".len());
+
+        println!(
+            "{:<width$}  {:<6}  {}",
+            "HOOK".bold(),
+            "STATUS".bold(),
+            "DETAILS".bold(),
+            width = width
+        );
+        for diagnosis in diagnoses {
+            if diagnosis.is_healthy() {
+                println!(
+                    "{:<width$}  {}",
+                    diagnosis.name,
+                    "ok".green(),
+                    width = width
+                );
+                continue;
+            }
+
+            for (index, problem) in diagnosis.problems.iter().enumerate() {
+                let name = if index == 0 {
+                    diagnosis.name.as_str()
+                } else {
+                    ""
+                };
+                let status = if index == 0 { "error" } else { "" };
+                println!(
+                    "{:<width$}  {:<6}  {}",
+                    name,
+                    status.red(),
+                    problem.describe(),
+                    width = width
+                );
+            }
+        }
+    }
+}
+
+fn is_executable(path: &Path) -> bool {
+    std::fs::metadata(path)
+        .map(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    async fn setup_test_git_repo() -> (TempDir, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+
+        (temp_dir, original_dir)
+    }
+
+    fn problems<'a>(diagnoses: &'a [Diagnosis], name: &str) -> &'a [Problem] {
+        &diagnoses
+            .iter()
+            .find(|diagnosis| diagnosis.name == name)
+            .unwrap()
+            .problems
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_diagnose_healthy_installation() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_hook("pre-commit".to_string(), "cargo fmt".to_string());
+        HookManager::create_hook_file("pre-commit").await.unwrap();
+
+        let diagnoses = Doctor::diagnose(&config).await.unwrap();
+        assert_eq!(diagnoses.len(), 1);
+        assert!(diagnoses[0].is_healthy());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_diagnose_and_fix_drift() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        for hook_name in ["pre-commit", "pre-push", "commit-msg", "post-merge"] {
+            config.add_hook(hook_name.to_string(), "true".to_string());
+        }
+
+        // pre-commit: 없음
+        // pre-push: 실행 권한 없음 + 오래된 내용
+        HookManager::create_hook_file("pre-push").await.unwrap();
+        let pre_push = Git::get_hook_path("pre-push").await.unwrap();
+        fs::write(
+            &pre_push,
+            "#!/bin/sh\n# Generated by rusky\nexec rusky run pre-push\n",
+        )
+        .await
+        .unwrap();
+        fs::set_permissions(&pre_push, std::fs::Permissions::from_mode(0o644))
+            .await
+            .unwrap();
+        // commit-msg: 다른 도구의 hook
+        let commit_msg = Git::get_hook_path("commit-msg").await.unwrap();
+        fs::write(&commit_msg, "#!/bin/sh\nnpx commitlint --edit $1\n")
+            .await
+            .unwrap();
+        // post-merge: 정상
+        HookManager::create_hook_file("post-merge").await.unwrap();
+        // post-checkout: 설정에 없는 rusky hook
+        HookManager::create_hook_file("post-checkout")
+            .await
+            .unwrap();
+
+        let diagnoses = Doctor::diagnose(&config).await.unwrap();
+        assert_eq!(problems(&diagnoses, "pre-commit"), &[Problem::Missing]);
+        assert!(problems(&diagnoses, "pre-push").contains(&Problem::NotExecutable));
+        assert!(problems(&diagnoses, "pre-push").contains(&Problem::Outdated));
+        assert_eq!(problems(&diagnoses, "commit-msg"), &[Problem::NotManaged]);
+        assert!(problems(&diagnoses, "post-merge").is_empty());
+        assert_eq!(
+            problems(&diagnoses, "post-checkout"),
+            &[Problem::NotConfigured]
+        );
+
+        Doctor::fix(&config, &diagnoses).await.unwrap();
+
+        let diagnoses = Doctor::diagnose(&config).await.unwrap();
+        assert!(
+            diagnoses.iter().all(Diagnosis::is_healthy),
+            "{:?}",
+            diagnoses
+        );
+        assert!(!Git::get_hook_path("post-checkout").await.unwrap().exists());
+        // 다른 도구의 hook은 백업된 뒤 교체됨
+        assert!(HookManager::get_backup_path("commit-msg")
+            .await
+            .unwrap()
+            .exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_diagnose_hooks_path_mismatch() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let config = Config {
+            hooks_path: Some(".rusky/_".to_string()),
+            ..Config::default()
+        };
+        Git::set_config("core.hooksPath", ".husky").await.unwrap();
+
+        let diagnoses = Doctor::diagnose(&config).await.unwrap();
+        assert_eq!(
+            problems(&diagnoses, "core.hooksPath"),
+            &[Problem::HooksPathMismatch {
+                expected: ".rusky/_".to_string(),
+                actual: Some(".husky".to_string()),
+            }]
+        );
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_diagnose_ignores_installed_binary_path() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_hook("pre-commit".to_string(), "true".to_string());
+
+        // 다른 설치 경로의 rusky가 만든 hook도 최신 상태로 봄
+        let hook_path = Git::get_hook_path("pre-commit").await.unwrap();
+        fs::write(
+            &hook_path,
+            HookManager::hook_script("pre-commit", "/opt/other/rusky"),
+        )
+        .await
+        .unwrap();
+        fs::set_permissions(&hook_path, std::fs::Permissions::from_mode(0o755))
+            .await
+            .unwrap();
+
+        let diagnoses = Doctor::diagnose(&config).await.unwrap();
+        assert!(!problems(&diagnoses, "pre-commit").contains(&Problem::Outdated));
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[test]
+    fn test_rusky_reachable_with_installed_binary() {
+        let script = HookManager::generate_hook_script("pre-commit");
+        assert!(Doctor::rusky_reachable(&script));
+    }
+}
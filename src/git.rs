@@ -1,15 +1,20 @@
 use anyhow::{anyhow, Result};
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::{Output, Stdio};
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
+use crate::staged::{chunk_files, MAX_ARGS_LENGTH};
+
 pub struct Git;
 
 impl Git {
     /// Git 저장소인지 확인
     pub async fn is_git_repo() -> Result<bool> {
         let output = Command::new("git")
-            .args(&["rev-parse", "--git-dir"])
+            .args(["rev-parse", "--git-dir"])
             .output()
             .await?;
 
@@ -19,7 +24,7 @@ impl Git {
     /// Git 저장소의 루트 경로 찾기
     pub async fn get_git_root() -> Result<PathBuf> {
         let output = Command::new("git")
-            .args(&["rev-parse", "--show-toplevel"])
+            .args(["rev-parse", "--show-toplevel"])
             .output()
             .await?;
 
@@ -33,9 +38,16 @@ impl Git {
     }
 
     /// Git hooks 디렉토리 경로 가져오기
+    ///
+    /// `git rev-parse --git-path hooks`를 사용하므로 `core.hooksPath`, linked worktree
+    /// (공통 Git 디렉토리의 hooks), submodule (`.git` 파일), bare 저장소와
+    /// `GIT_DIR`/`GIT_WORK_TREE` 환경 변수를 모두 git과 같은 방식으로 따릅니다.
     pub async fn get_hooks_dir() -> Result<PathBuf> {
-        let git_root = Self::get_git_root().await?;
-        Ok(git_root.join(".git").join("hooks"))
+        if !Self::is_git_repo().await? {
+            return Err(anyhow!("Not in a git repository"));
+        }
+
+        Self::get_git_path("hooks").await
     }
 
     /// Git hooks 디렉토리 설정
@@ -62,11 +74,456 @@ impl Git {
         Ok(hook_path.exists())
     }
 
+    /// 커밋에 포함될 (staged) 파일 목록 가져오기 (추가/복사/수정/이름변경만)
+    pub async fn get_staged_files() -> Result<Vec<String>> {
+        let output = Command::new("git")
+            .args([
+                "diff",
+                "--cached",
+                "--name-only",
+                "--diff-filter=ACMR",
+                "-z",
+            ])
+            .output()
+            .await?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Failed to get staged files: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(split_nul(&output.stdout))
+    }
+
+    /// 두 커밋 사이에 바뀐 파일 목록
+    pub async fn get_changed_files(from: &str, to: &str) -> Result<Vec<String>> {
+        let output = Self::output(&["diff", "--name-only", "-z", from, to]).await?;
+        Ok(split_nul(&output.stdout))
+    }
+
+    /// `rev`의 커밋 중 어느 원격 브랜치에도 없는 커밋이 바꾼 파일 목록
+    pub async fn get_unpushed_files(rev: &str) -> Result<Vec<String>> {
+        let output = Self::output(&[
+            "log",
+            "--name-only",
+            "-z",
+            "--format=",
+            rev,
+            "--not",
+            "--remotes",
+        ])
+        .await?;
+
+        let mut files = split_nul(&output.stdout);
+        files.sort();
+        files.dedup();
+        Ok(files)
+    }
+
+    /// 커밋에 들어 있는 모든 파일 목록
+    pub async fn get_tree_files(rev: &str) -> Result<Vec<String>> {
+        let output = Self::output(&["ls-tree", "-r", "--name-only", "-z", rev]).await?;
+        Ok(split_nul(&output.stdout))
+    }
+
+    /// 로컬 저장소에 있는 커밋인지 확인
+    pub async fn commit_exists(rev: &str) -> Result<bool> {
+        let status = Command::new("git")
+            .args(["cat-file", "-e", &format!("{}^{{commit}}", rev)])
+            .stderr(Stdio::null())
+            .status()
+            .await?;
+        Ok(status.success())
+    }
+
+    /// staged 변경의 diff (문맥 줄 없이, `files`가 있으면 그 파일만)
+    ///
+    /// textconv 설정이 실제 staged 내용을 가리지 않도록 `--no-textconv`를 씁니다.
+    pub async fn get_staged_diff(files: &[String]) -> Result<String> {
+        let chunks = if files.is_empty() {
+            vec![Vec::new()]
+        } else {
+            chunk_files(files, MAX_ARGS_LENGTH)
+        };
+
+        let mut diff = String::new();
+        for chunk in chunks {
+            let mut args = vec![
+                "-c",
+                "core.quotePath=false",
+                "diff",
+                "--cached",
+                "-U0",
+                "--no-color",
+                "--no-ext-diff",
+                "--no-textconv",
+                "--diff-filter=ACMR",
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                "--",
+            ];
+            args.extend(chunk.iter().map(String::as_str));
+
+            let output = Command::new("git").args(&args).output().await?;
+            if !output.status.success() {
+                return Err(anyhow!(
+                    "Failed to get staged diff: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                ));
+            }
+            diff.push_str(&String::from_utf8_lossy(&output.stdout));
+        }
+
+        Ok(diff)
+    }
+
+    /// 파일 하나의 stage되지 않은 변경 diff (문맥 줄 없이)
+    pub async fn get_unstaged_diff(file: &str) -> Result<String> {
+        let output = Self::output(&[
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "-U0",
+            "--no-color",
+            "--no-ext-diff",
+            "--no-textconv",
+            "--src-prefix=a/",
+            "--dst-prefix=b/",
+            "--",
+            file,
+        ])
+        .await?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// staged 파일의 index 안 blob 크기 (바이트, submodule 제외)
+    pub async fn get_staged_sizes(files: &[String]) -> Result<Vec<(String, u64)>> {
+        let entries: Vec<IndexEntry> = Self::get_index_entries(files)
+            .await?
+            .into_iter()
+            .filter(|entry| entry.mode != "160000")
+            .collect();
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let input: String = entries
+            .iter()
+            .map(|entry| format!("{}\n", entry.object))
+            .collect();
+        let output = Self::output_with_input(
+            &["cat-file", "--batch-check=%(objectsize)"],
+            input.into_bytes(),
+        )
+        .await?;
+
+        let sizes = String::from_utf8(output.stdout)?;
+        Ok(entries
+            .into_iter()
+            .zip(sizes.lines())
+            .filter_map(|(entry, size)| size.trim().parse().ok().map(|size| (entry.path, size)))
+            .collect())
+    }
+
+    /// staged 일반 파일의 index 안 내용 (submodule과 symlink 제외)
+    pub async fn get_staged_blobs(files: &[String]) -> Result<Vec<StagedBlob>> {
+        let entries: Vec<IndexEntry> = Self::get_index_entries(files)
+            .await?
+            .into_iter()
+            .filter(|entry| entry.mode == "100644" || entry.mode == "100755")
+            .collect();
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let input: String = entries
+            .iter()
+            .map(|entry| format!("{}\n", entry.object))
+            .collect();
+        let output = Self::output_with_input(&["cat-file", "--batch"], input.into_bytes()).await?;
+
+        // <object> <type> <size>\n<content>\n
+        let mut blobs = Vec::new();
+        let mut rest = output.stdout.as_slice();
+        for entry in entries {
+            let header_end = rest
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .ok_or_else(|| anyhow!("Unexpected git cat-file output"))?;
+            let header = String::from_utf8_lossy(&rest[..header_end]).into_owned();
+            let size: usize = header
+                .rsplit(' ')
+                .next()
+                .and_then(|size| size.parse().ok())
+                .ok_or_else(|| anyhow!("Unexpected git cat-file header: {}", header))?;
+
+            let content_start = header_end + 1;
+            let content = rest
+                .get(content_start..content_start + size)
+                .ok_or_else(|| anyhow!("Unexpected end of git cat-file output"))?;
+            blobs.push(StagedBlob {
+                path: entry.path,
+                mode: entry.mode,
+                content: content.to_vec(),
+            });
+            rest = rest.get(content_start + size + 1..).unwrap_or_default();
+        }
+
+        Ok(blobs)
+    }
+
+    /// 작업 트리는 그대로 두고 index의 파일 내용만 바꾸기
+    pub async fn stage_blob(path: &str, mode: &str, content: &[u8]) -> Result<()> {
+        let output = Self::output_with_input(
+            &["hash-object", "-w", "--no-filters", "--stdin"],
+            content.to_vec(),
+        )
+        .await?;
+        let object = String::from_utf8(output.stdout)?.trim().to_string();
+
+        let cacheinfo = format!("{},{},{}", mode, object, path);
+        Self::output(&["update-index", "--cacheinfo", &cacheinfo]).await?;
+        Ok(())
+    }
+
+    /// staged 파일의 경로와 index 안 object id
+    pub async fn get_staged_objects(files: &[String]) -> Result<Vec<(String, String)>> {
+        Ok(Self::get_index_entries(files)
+            .await?
+            .into_iter()
+            .map(|entry| (entry.path, entry.object))
+            .collect())
+    }
+
+    /// 현재 index 전체를 tree object로 저장하고 그 id 반환
+    pub async fn write_tree() -> Result<String> {
+        let output = Self::output(&["write-tree"]).await?;
+        Ok(String::from_utf8(output.stdout)?.trim().to_string())
+    }
+
+    /// 내용의 blob object id (저장소에 쓰지는 않음)
+    pub async fn hash_object(content: &[u8]) -> Result<String> {
+        let output =
+            Self::output_with_input(&["hash-object", "--stdin"], content.to_vec()).await?;
+        Ok(String::from_utf8(output.stdout)?.trim().to_string())
+    }
+
+    /// index에 있는 모든 파일 경로
+    pub async fn get_index_files() -> Result<Vec<String>> {
+        let output = Self::output(&["ls-files", "-z"]).await?;
+        Ok(split_nul(&output.stdout))
+    }
+
+    /// `files`에 해당하는 index 항목 (`git ls-files --stage`)
+    async fn get_index_entries(files: &[String]) -> Result<Vec<IndexEntry>> {
+        let files: HashSet<&str> = files.iter().map(String::as_str).collect();
+        let output = Self::output(&["ls-files", "--stage", "-z"]).await?;
+
+        // <mode> <object> <stage>\t<path>
+        let mut entries = Vec::new();
+        for entry in split_nul(&output.stdout) {
+            let Some((meta, path)) = entry.split_once('\t') else {
+                continue;
+            };
+            let mut fields = meta.split_whitespace();
+            let (Some(mode), Some(object)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            if files.contains(path) {
+                entries.push(IndexEntry {
+                    path: path.to_string(),
+                    mode: mode.to_string(),
+                    object: object.to_string(),
+                });
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// git이 binary로 판단하는 staged 파일 (`.gitattributes` 반영)
+    pub async fn get_staged_binary_files(files: &[String]) -> Result<Vec<String>> {
+        let mut binary_files = Vec::new();
+
+        for chunk in chunk_files(files, MAX_ARGS_LENGTH) {
+            let mut args = vec![
+                "diff",
+                "--cached",
+                "--numstat",
+                "--no-renames",
+                "--diff-filter=ACM",
+                "-z",
+                "--",
+            ];
+            args.extend(chunk.iter().map(String::as_str));
+            let output = Self::output(&args).await?;
+
+            // binary 파일은 추가/삭제 줄 수가 `-`로 표시됨
+            binary_files.extend(
+                split_nul(&output.stdout)
+                    .into_iter()
+                    .filter_map(|entry| entry.strip_prefix("-\t-\t").map(String::from)),
+            );
+        }
+
+        Ok(binary_files)
+    }
+
+    /// Git 디렉토리 안의 경로 가져오기 (`git rev-parse --git-path`)
+    pub async fn get_git_path(path: &str) -> Result<PathBuf> {
+        let output = Self::output(&["rev-parse", "--git-path", path]).await?;
+        let git_path = PathBuf::from(String::from_utf8(output.stdout)?.trim());
+
+        if git_path.is_absolute() {
+            Ok(git_path)
+        } else {
+            Ok(std::env::current_dir()?.join(git_path))
+        }
+    }
+
+    /// 작업 트리에만 있는 (unstaged) 변경이 있는 파일 목록
+    pub async fn get_unstaged_files() -> Result<Vec<String>> {
+        let output = Self::output(&["diff", "--name-only", "-z"]).await?;
+        Ok(split_nul(&output.stdout))
+    }
+
+    /// 무시되지 않은 untracked 파일 목록
+    pub async fn get_untracked_files() -> Result<Vec<String>> {
+        let output =
+            Self::output(&["ls-files", "--others", "--exclude-standard", "-z"]).await?;
+        Ok(split_nul(&output.stdout))
+    }
+
+    /// unstaged 변경을 `git apply`로 다시 적용할 수 있는 patch로 가져오기
+    pub async fn get_unstaged_patch() -> Result<Vec<u8>> {
+        let output = Self::output(&[
+            "diff",
+            "--binary",
+            "--no-color",
+            "--no-ext-diff",
+            "--src-prefix=a/",
+            "--dst-prefix=b/",
+        ])
+        .await?;
+        Ok(output.stdout)
+    }
+
+    /// patch 파일을 작업 트리에 적용하고 성공 여부 반환
+    pub async fn apply_patch(patch_path: &Path) -> Result<bool> {
+        let output = Command::new("git")
+            .args(["apply", "--whitespace=nowarn", "--binary"])
+            .arg(patch_path)
+            .output()
+            .await?;
+
+        Ok(output.status.success())
+    }
+
+    /// 작업 트리의 파일을 index 내용으로 되돌리기
+    pub async fn checkout_files(files: &[String]) -> Result<()> {
+        // 큰 commit에서도 명령어 인자 길이 제한을 넘지 않도록 나눠서 실행
+        for chunk in chunk_files(files, MAX_ARGS_LENGTH) {
+            let mut args = vec!["checkout", "--"];
+            args.extend(chunk.iter().map(String::as_str));
+            Self::output(&args).await?;
+        }
+        Ok(())
+    }
+
+    /// 파일을 index에 추가
+    pub async fn add_files(files: &[String]) -> Result<()> {
+        for chunk in chunk_files(files, MAX_ARGS_LENGTH) {
+            let mut args = vec!["add", "--"];
+            args.extend(chunk.iter().map(String::as_str));
+            Self::output(&args).await?;
+        }
+        Ok(())
+    }
+
+    /// 현재 index와 작업 트리를 담은 stash commit 만들기 (stash 목록에는 추가하지 않음)
+    pub async fn create_stash() -> Result<Option<String>> {
+        let output = Command::new("git")
+            .args(["stash", "create", "rusky: automatic backup"])
+            .output()
+            .await?;
+
+        // 첫 commit 전이거나 변경이 없으면 만들 수 없음
+        let hash = String::from_utf8(output.stdout)?.trim().to_string();
+        if !output.status.success() || hash.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(hash))
+    }
+
+    /// 현재 체크아웃된 브랜치 이름 (detached HEAD면 None)
+    pub async fn get_current_branch() -> Result<Option<String>> {
+        let output = Command::new("git")
+            .args(["symbolic-ref", "--short", "-q", "HEAD"])
+            .output()
+            .await?;
+
+        // detached HEAD면 종료 코드 1, 저장소가 아니면 128
+        match output.status.code() {
+            Some(0) => Ok(Some(String::from_utf8(output.stdout)?.trim().to_string())),
+            Some(1) => Ok(None),
+            _ => Err(anyhow!(
+                "Failed to resolve the current branch: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            )),
+        }
+    }
+
+    /// git 명령어를 실행하고 실패하면 stderr를 담은 에러 반환
+    async fn output(args: &[&str]) -> Result<Output> {
+        let output = Command::new("git").args(args).output().await?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "git {} failed: {}",
+                args.first().copied().unwrap_or_default(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(output)
+    }
+
+    /// stdin에 `input`을 넘겨 git 명령어 실행
+    async fn output_with_input(args: &[&str], input: Vec<u8>) -> Result<Output> {
+        let mut child = Command::new("git")
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        let mut stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("Failed to open git {} stdin", args[0]))?;
+        // 출력이 pipe를 채워 멈추지 않도록 입력은 따로 씀
+        let writer = tokio::spawn(async move { stdin.write_all(&input).await });
+        let output = child.wait_with_output().await?;
+        writer.await??;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "git {} failed: {}",
+                args.first().copied().unwrap_or_default(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+
+        Ok(output)
+    }
+
     /// Git 설정 값 가져오기
-    #[allow(dead_code)]
     pub async fn get_config(key: &str) -> Result<Option<String>> {
         let output = Command::new("git")
-            .args(&["config", "--get", key])
+            .args(["config", "--get", key])
             .output()
             .await?;
 
@@ -79,10 +536,9 @@ impl Git {
     }
 
     /// Git 설정 값 설정하기
-    #[allow(dead_code)]
     pub async fn set_config(key: &str, value: &str) -> Result<()> {
         let output = Command::new("git")
-            .args(&["config", key, value])
+            .args(["config", key, value])
             .output()
             .await?;
 
@@ -92,15 +548,56 @@ impl Git {
 
         Ok(())
     }
+
+    /// Git 설정 값 제거하기
+    pub async fn unset_config(key: &str) -> Result<()> {
+        let output = Command::new("git")
+            .args(["config", "--unset", key])
+            .output()
+            .await?;
+
+        // 종료 코드 5: 설정되어 있지 않음
+        if !output.status.success() && output.status.code() != Some(5) {
+            return Err(anyhow!("Failed to unset git config: {}", key));
+        }
+
+        Ok(())
+    }
+}
+
+/// index에 있는 파일 하나
+struct IndexEntry {
+    path: String,
+    mode: String,
+    object: String,
+}
+
+/// index에 있는 파일의 내용
+pub struct StagedBlob {
+    pub path: String,
+    /// 파일 모드 (`100644`, `100755`)
+    pub mode: String,
+    pub content: Vec<u8>,
+}
+
+/// `-z` 옵션으로 출력된 NUL 구분 경로 목록 파싱
+fn split_nul(output: &[u8]) -> Vec<String> {
+    output
+        .split(|byte| *byte == 0)
+        .filter(|path| !path.is_empty())
+        .map(|path| String::from_utf8_lossy(path).into_owned())
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
     use tempfile::TempDir;
     use std::env;
 
     #[tokio::test]
+    #[serial]
     async fn test_is_git_repo() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -113,7 +610,7 @@ mod tests {
         
         // Git 저장소 초기화
         let output = tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await;
         
@@ -127,6 +624,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_get_git_root() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -140,7 +638,7 @@ mod tests {
         
         // Git 저장소 초기화
         let output = tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await;
         
@@ -157,6 +655,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_get_hooks_dir() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -164,20 +663,21 @@ mod tests {
         // 임시 디렉토리로 이동하고 Git 저장소 초기화
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
         
         let hooks_dir = Git::get_hooks_dir().await.unwrap();
         let expected_path = temp_dir.path().join(".git").join("hooks");
-        assert_eq!(hooks_dir, expected_path);
+        assert_eq!(hooks_dir.canonicalize().unwrap(), expected_path.canonicalize().unwrap());
         
         // 원래 디렉토리로 복원
         env::set_current_dir(original_dir).unwrap();
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_setup_hooks_dir() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -185,7 +685,7 @@ mod tests {
         // 임시 디렉토리로 이동하고 Git 저장소 초기화
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
@@ -204,6 +704,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_get_hook_path() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -211,20 +712,25 @@ mod tests {
         // 임시 디렉토리로 이동하고 Git 저장소 초기화
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
         
         let hook_path = Git::get_hook_path("pre-commit").await.unwrap();
-        let expected_path = temp_dir.path().join(".git").join("hooks").join("pre-commit");
-        assert_eq!(hook_path, expected_path);
+        let expected_path = temp_dir.path().join(".git").join("hooks");
+        assert_eq!(hook_path.file_name().unwrap(), "pre-commit");
+        assert_eq!(
+            hook_path.parent().unwrap().canonicalize().unwrap(),
+            expected_path.canonicalize().unwrap()
+        );
         
         // 원래 디렉토리로 복원
         env::set_current_dir(original_dir).unwrap();
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_hook_exists() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -232,7 +738,7 @@ mod tests {
         // 임시 디렉토리로 이동하고 Git 저장소 초기화
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
@@ -252,4 +758,241 @@ mod tests {
         // 원래 디렉토리로 복원
         env::set_current_dir(original_dir).unwrap();
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_staged_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+        
+        tokio::fs::write("staged.txt", "staged").await.unwrap();
+        tokio::fs::write("with space.txt", "staged").await.unwrap();
+        tokio::fs::write("unstaged.txt", "unstaged").await.unwrap();
+        tokio::process::Command::new("git")
+            .args(["add", "staged.txt", "with space.txt"])
+            .output()
+            .await
+            .unwrap();
+        
+        let staged = Git::get_staged_files().await.unwrap();
+        assert_eq!(staged, vec!["staged.txt".to_string(), "with space.txt".to_string()]);
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_hooks_dir_honors_core_hooks_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+        
+        Git::set_config("core.hooksPath", ".githooks").await.unwrap();
+        let git_root = Git::get_git_root().await.unwrap();
+        assert_eq!(Git::get_hooks_dir().await.unwrap(), git_root.join(".githooks"));
+        
+        // 하위 디렉토리에서도 저장소 루트 기준으로 해석됨
+        tokio::fs::create_dir("sub").await.unwrap();
+        env::set_current_dir("sub").unwrap();
+        let hooks_dir = Git::get_hooks_dir().await.unwrap();
+        assert_eq!(
+            hooks_dir.components().collect::<PathBuf>(),
+            git_root.join("sub").join("..").join(".githooks")
+        );
+        env::set_current_dir(&git_root).unwrap();
+        
+        Git::unset_config("core.hooksPath").await.unwrap();
+        assert_eq!(Git::get_config("core.hooksPath").await.unwrap(), None);
+        // 설정되지 않은 값을 다시 제거해도 에러가 아님
+        assert!(Git::unset_config("core.hooksPath").await.is_ok());
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    async fn git(args: &[&str]) {
+        let output = tokio::process::Command::new("git")
+            .args(args)
+            .output()
+            .await
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    async fn init_repo_with_commit(path: &Path) {
+        git(&["init", &path.to_string_lossy()]).await;
+        git(&[
+            "-C", &path.to_string_lossy(),
+            "-c", "user.name=Test User",
+            "-c", "user.email=test@example.com",
+            "commit", "--allow-empty", "-m", "initial",
+        ])
+        .await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_hooks_dir_in_linked_worktree() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        
+        init_repo_with_commit(&root.join("main")).await;
+        env::set_current_dir(root.join("main")).unwrap();
+        git(&["worktree", "add", "../linked"]).await;
+        
+        // linked worktree의 .git은 파일이며 hooks는 공통 Git 디렉토리에 있음
+        env::set_current_dir(root.join("linked")).unwrap();
+        assert!(Path::new(".git").is_file());
+        let hooks_dir = Git::get_hooks_dir().await.unwrap();
+        assert_eq!(hooks_dir, root.join("main").join(".git").join("hooks"));
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_hooks_dir_in_submodule() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        
+        init_repo_with_commit(&root.join("child")).await;
+        git(&["init", &root.join("parent").to_string_lossy()]).await;
+        env::set_current_dir(root.join("parent")).unwrap();
+        git(&[
+            "-c", "protocol.file.allow=always",
+            "submodule", "add", "../child", "child",
+        ])
+        .await;
+        
+        env::set_current_dir(root.join("parent").join("child")).unwrap();
+        assert!(Path::new(".git").is_file());
+        let hooks_dir = Git::get_hooks_dir().await.unwrap();
+        assert_eq!(
+            hooks_dir.canonicalize().unwrap(),
+            root.join("parent").join(".git").join("modules").join("child").join("hooks")
+        );
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_hooks_dir_in_bare_repository() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        
+        git(&["init", "--bare", &root.join("repo.git").to_string_lossy()]).await;
+        env::set_current_dir(root.join("repo.git")).unwrap();
+        
+        assert!(Git::is_git_repo().await.unwrap());
+        assert!(Git::get_git_root().await.is_err());
+        let hooks_dir = Git::get_hooks_dir().await.unwrap();
+        assert_eq!(hooks_dir, root.join("repo.git").join("hooks"));
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_current_branch() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        let root = temp_dir.path().canonicalize().unwrap();
+        
+        init_repo_with_commit(&root).await;
+        env::set_current_dir(&root).unwrap();
+        git(&["checkout", "-q", "-b", "feat/ABC-1-branch"]).await;
+        assert_eq!(
+            Git::get_current_branch().await.unwrap(),
+            Some("feat/ABC-1-branch".to_string())
+        );
+        
+        git(&["checkout", "-q", "--detach"]).await;
+        assert_eq!(Git::get_current_branch().await.unwrap(), None);
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_staged_blobs_and_stage_blob() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        fs::write("a.txt", "staged\n").await.unwrap();
+        fs::write("empty.txt", "").await.unwrap();
+        git(&["add", "a.txt", "empty.txt"]).await;
+        fs::write("a.txt", "staged\nunstaged\n").await.unwrap();
+
+        let files = vec!["a.txt".to_string(), "empty.txt".to_string()];
+        let blobs = Git::get_staged_blobs(&files).await.unwrap();
+        let contents: Vec<(&str, &[u8])> = blobs
+            .iter()
+            .map(|blob| (blob.path.as_str(), blob.content.as_slice()))
+            .collect();
+        assert_eq!(contents, vec![("a.txt", &b"staged\n"[..]), ("empty.txt", &b""[..])]);
+        assert_eq!(blobs[0].mode, "100644");
+
+        // index만 바뀌고 작업 트리는 그대로
+        Git::stage_blob("a.txt", "100644", b"fixed\n").await.unwrap();
+        let blobs = Git::get_staged_blobs(&files[..1]).await.unwrap();
+        assert_eq!(blobs[0].content, b"fixed\n");
+        assert_eq!(fs::read_to_string("a.txt").await.unwrap(), "staged\nunstaged\n");
+
+        assert_eq!(Git::get_index_files().await.unwrap(), files);
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_add_and_checkout_many_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        git(&["init", "-q"]).await;
+
+        // 경로를 모두 합치면 한 번에 넘기는 인자 길이 제한보다 김
+        let files: Vec<String> = (0..1500)
+            .map(|index| format!("{:0>60}.txt", index))
+            .collect();
+        assert!(chunk_files(&files, MAX_ARGS_LENGTH).len() > 1);
+        for file in &files {
+            fs::write(file, "staged\n").await.unwrap();
+        }
+
+        Git::add_files(&files).await.unwrap();
+        assert_eq!(Git::get_index_files().await.unwrap().len(), files.len());
+
+        for file in &files {
+            fs::write(file, "unstaged\n").await.unwrap();
+        }
+        Git::checkout_files(&files).await.unwrap();
+        assert!(Git::get_unstaged_files().await.unwrap().is_empty());
+        assert_eq!(fs::read_to_string(&files[1499]).await.unwrap(), "staged\n");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
 }
@@ -1,18 +1,43 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use colored::*;
 use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
 use tokio::fs;
 
+use crate::config::Config;
 use crate::git::Git;
 
+/// rusky가 생성한 hook 파일에 들어가는 표식
+const HOOK_MARKER: &str = "Generated by rusky";
+
+/// 설치 전에 있던 hook 파일을 옮겨 두는 디렉토리 (hooks 디렉토리 기준)
+const BACKUP_DIR: &str = "rusky_backup";
+
 pub struct HookManager;
 
 impl HookManager {
     /// Hook 파일 생성
-    pub async fn create_hook_file(hook_name: &str, command: &str) -> Result<()> {
+    pub async fn create_hook_file(hook_name: &str) -> Result<()> {
         let hook_path = Git::get_hook_path(hook_name).await?;
+        if let Some(hooks_dir) = hook_path.parent() {
+            fs::create_dir_all(hooks_dir).await?;
+        }
+
+        // 직접 작성했거나 다른 도구가 만든 hook은 덮어쓰기 전에 백업
+        if let Some(backup_path) = Self::backup_existing_hook(hook_name).await? {
+            eprintln!(
+                "{}",
+                format!(
+                    "⚠️  Backed up existing {} hook to {}",
+                    hook_name,
+                    backup_path.display()
+                )
+                .yellow()
+            );
+        }
 
         // Hook 스크립트 내용 생성
-        let hook_content = Self::generate_hook_script(command);
+        let hook_content = Self::generate_hook_script(hook_name);
 
         // Hook 파일 작성
         fs::write(&hook_path, hook_content).await?;
@@ -26,127 +51,279 @@ impl HookManager {
     }
 
     /// Hook 파일 삭제
+    ///
+    /// rusky가 만든 파일만 삭제하고, 설치 시 백업해 둔 원래 hook이 있으면 복원합니다.
     pub async fn remove_hook_file(hook_name: &str) -> Result<()> {
         let hook_path = Git::get_hook_path(hook_name).await?;
 
         if hook_path.exists() {
+            if !Self::is_rusky_hook(hook_name).await? {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "⚠️  Leaving {} in place: it was not created by rusky",
+                        hook_path.display()
+                    )
+                    .yellow()
+                );
+                return Ok(());
+            }
             fs::remove_file(&hook_path).await?;
         }
 
+        if Self::restore_hook(hook_name).await? {
+            eprintln!(
+                "{}",
+                format!("♻️  Restored original {} hook", hook_name).dimmed()
+            );
+        }
+
         Ok(())
     }
 
-    /// Hook 스크립트 생성
-    fn generate_hook_script(command: &str) -> String {
-        format!(
-            r#"#!/bin/sh
-# Generated by rusky
+    /// hook 파일을 쓸 디렉토리 준비
+    ///
+    /// `hooks_path`가 설정되어 있으면 그 디렉토리를 `core.hooksPath`로 지정하고,
+    /// 다른 도구가 이미 `core.hooksPath`를 사용 중이면 `force` 없이는 변경하지 않습니다.
+    pub async fn prepare_hooks_dir(config: &Config, force: bool) -> Result<()> {
+        let current = Git::get_config("core.hooksPath").await?;
+
+        let Some(hooks_path) = &config.hooks_path else {
+            if let Some(current) = current {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "⚠️  core.hooksPath is set to {} (possibly by another tool such as husky); git runs hooks from there, so rusky installs its hooks into that directory.",
+                        current
+                    )
+                    .yellow()
+                );
+            }
+            return Git::setup_hooks_dir().await;
+        };
+
+        if let Some(current) = current.filter(|current| !same_hooks_path(current, hooks_path)) {
+            if !force {
+                return Err(anyhow!(
+                    "core.hooksPath is already set to {} (possibly by another tool such as husky). Run 'rusky install --force' to point it at {} instead.",
+                    current,
+                    hooks_path
+                ));
+            }
+            eprintln!(
+                "{}",
+                format!("⚠️  Replacing core.hooksPath {} with {}", current, hooks_path).yellow()
+            );
+        }
 
-set -e
+        Git::set_config("core.hooksPath", hooks_path).await?;
+        Git::setup_hooks_dir().await?;
 
-echo "🐺 rusky > {hook_name}"
+        // shim은 각자 설치하므로 커밋하지 않음
+        let gitignore = Git::get_hooks_dir().await?.join(".gitignore");
+        if !gitignore.exists() {
+            fs::write(gitignore, "*\n").await?;
+        }
 
-# Execute the command
-{command}
+        Ok(())
+    }
 
-echo "✅ rusky > {hook_name} completed"
+    /// rusky가 설정한 `core.hooksPath` 해제
+    pub async fn release_hooks_path(config: &Config) -> Result<()> {
+        let Some(hooks_path) = &config.hooks_path else {
+            return Ok(());
+        };
+
+        let current = Git::get_config("core.hooksPath").await?;
+        if !current.is_some_and(|current| same_hooks_path(&current, hooks_path)) {
+            return Ok(());
+        }
+
+        let hooks_dir = Git::get_hooks_dir().await?;
+        Git::unset_config("core.hooksPath").await?;
+
+        let gitignore = hooks_dir.join(".gitignore");
+        if gitignore.exists() {
+            fs::remove_file(gitignore).await?;
+        }
+        // 비어 있을 때만 삭제됨
+        let _ = fs::remove_dir(&hooks_dir).await;
+
+        Ok(())
+    }
+
+    /// Hook 스크립트 생성
+    ///
+    /// 생성되는 파일은 `rusky run <hook>`을 호출하는 shim이며, 실제 명령어는
+    /// hook 실행 시점에 `.rusky/config.json`에서 읽어옵니다.
+    pub fn generate_hook_script(hook_name: &str) -> String {
+        let rusky_bin = std::env::current_exe()
+            .map(|path| path.to_string_lossy().into_owned())
+            .unwrap_or_else(|_| "rusky".to_string());
+
+        Self::hook_script(hook_name, &rusky_bin)
+    }
+
+    /// `rusky_bin`을 먼저 찾아 실행하는 hook 스크립트
+    pub fn hook_script(hook_name: &str, rusky_bin: &str) -> String {
+        format!(
+            r#"#!/bin/sh
+# Generated by rusky - edit .rusky/config.json instead of this file
+
+if [ -n "$RUSKY_BIN" ]; then
+  rusky="$RUSKY_BIN"
+elif [ -x {rusky_bin} ]; then
+  rusky={rusky_bin}
+elif command -v rusky >/dev/null 2>&1; then
+  rusky="rusky"
+elif [ -x "./node_modules/.bin/rusky" ]; then
+  rusky="./node_modules/.bin/rusky"
+else
+  echo "rusky > rusky binary not found, skipping {hook_name} hook" >&2
+  exit 0
+fi
+
+exec "$rusky" run {hook_name} "$@"
 "#,
-            hook_name = "hook",
-            command = command
+            hook_name = hook_name,
+            rusky_bin = shell_quote(rusky_bin)
         )
     }
 
-    /// 모든 Hook 파일 백업
-    #[allow(dead_code)]
-    pub async fn backup_existing_hooks() -> Result<()> {
+    /// 생성된 hook 스크립트에 기록된 rusky 바이너리 경로 읽기
+    pub fn installed_rusky_bin(script: &str) -> Option<String> {
+        let quoted = script
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("rusky='"))?;
+        let quoted = quoted.strip_suffix('\'')?;
+        Some(quoted.replace("'\\''", "'"))
+    }
+
+    /// 백업된 원래 hook 파일의 경로
+    pub async fn get_backup_path(hook_name: &str) -> Result<PathBuf> {
         let hooks_dir = Git::get_hooks_dir().await?;
-        let backup_dir = hooks_dir.join("rusky_backup");
+        Ok(hooks_dir.join(BACKUP_DIR).join(hook_name))
+    }
 
-        if !backup_dir.exists() {
-            fs::create_dir(&backup_dir).await?;
+    /// rusky가 만들지 않은 기존 hook 파일을 백업 디렉토리로 옮기고 백업 경로 반환
+    pub async fn backup_existing_hook(hook_name: &str) -> Result<Option<PathBuf>> {
+        let hook_path = Git::get_hook_path(hook_name).await?;
+        if !hook_path.is_file() || Self::is_rusky_hook(hook_name).await? {
+            return Ok(None);
         }
 
-        // 기존 hook 파일들을 백업
-        let mut entries = fs::read_dir(&hooks_dir).await?;
-        while let Some(entry) = entries.next_entry().await? {
-            let path = entry.path();
-            if path.is_file()
-                && !path
-                    .file_name()
-                    .unwrap()
-                    .to_str()
-                    .unwrap()
-                    .starts_with("rusky_backup")
-            {
-                let file_name = path.file_name().unwrap();
-                let backup_path = backup_dir.join(file_name);
-                fs::copy(&path, &backup_path).await?;
-            }
+        let backup_path = Self::get_backup_path(hook_name).await?;
+        if backup_path.exists() {
+            return Err(anyhow!(
+                "{} is not managed by rusky, but a backup already exists at {}. Move one of them away and try again.",
+                hook_path.display(),
+                backup_path.display()
+            ));
         }
 
-        Ok(())
-    }
+        if let Some(backup_dir) = backup_path.parent() {
+            fs::create_dir_all(backup_dir).await?;
+        }
+        // rename은 실행 권한도 그대로 유지
+        fs::rename(&hook_path, &backup_path).await?;
 
-    /// 백업된 Hook 파일 복원
-    #[allow(dead_code)]
-    pub async fn restore_hooks() -> Result<()> {
-        let hooks_dir = Git::get_hooks_dir().await?;
-        let backup_dir = hooks_dir.join("rusky_backup");
+        Ok(Some(backup_path))
+    }
 
-        if !backup_dir.exists() {
-            return Ok(());
+    /// 백업된 원래 hook 파일을 복원 (백업이 없으면 false)
+    pub async fn restore_hook(hook_name: &str) -> Result<bool> {
+        let backup_path = Self::get_backup_path(hook_name).await?;
+        if !backup_path.exists() {
+            return Ok(false);
         }
 
-        let mut entries = fs::read_dir(&backup_dir).await?;
-        while let Some(entry) = entries.next_entry().await? {
-            let path = entry.path();
-            if path.is_file() {
-                let file_name = path.file_name().unwrap();
-                let restore_path = hooks_dir.join(file_name);
-                fs::copy(&path, &restore_path).await?;
-            }
+        let hook_path = Git::get_hook_path(hook_name).await?;
+        if hook_path.exists() {
+            return Ok(false);
         }
+        fs::rename(&backup_path, &hook_path).await?;
 
-        // 백업 디렉토리 삭제
-        fs::remove_dir_all(&backup_dir).await?;
+        // 비어 있을 때만 삭제됨
+        if let Some(backup_dir) = backup_path.parent() {
+            let _ = fs::remove_dir(backup_dir).await;
+        }
 
-        Ok(())
+        Ok(true)
     }
 
     /// Hook 파일이 rusky에 의해 관리되는지 확인
-    #[allow(dead_code)]
     pub async fn is_rusky_hook(hook_name: &str) -> Result<bool> {
         let hook_path = Git::get_hook_path(hook_name).await?;
 
-        if !hook_path.exists() {
+        if !hook_path.is_file() {
             return Ok(false);
         }
 
-        let content = fs::read_to_string(&hook_path).await?;
-        Ok(content.contains("Generated by rusky"))
+        // 바이너리 hook도 있을 수 있으므로 바이트 단위로 검사
+        let content = fs::read(&hook_path).await?;
+        Ok(content
+            .windows(HOOK_MARKER.len())
+            .any(|window| window == HOOK_MARKER.as_bytes()))
     }
 }
 
+/// 두 hooks 경로가 같은 디렉토리를 가리키는지 비교 (`./`, 끝의 `/` 무시)
+pub(crate) fn same_hooks_path(a: &str, b: &str) -> bool {
+    let normalize = |path: &str| path.trim_start_matches("./").trim_end_matches('/').to_string();
+    normalize(a) == normalize(b)
+}
+
+/// 문자열을 셸에서 안전하게 사용할 수 있도록 작은따옴표로 감싸기
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
     use tempfile::TempDir;
     use std::env;
     use std::os::unix::fs::PermissionsExt;
 
     #[tokio::test]
     async fn test_generate_hook_script() {
-        let script = HookManager::generate_hook_script("echo 'test'");
+        let script = HookManager::generate_hook_script("pre-commit");
         
         assert!(script.contains("#!/bin/sh"));
         assert!(script.contains("Generated by rusky"));
-        assert!(script.contains("set -e"));
-        assert!(script.contains("echo 'test'"));
-        assert!(script.contains("🐺 rusky > hook"));
-        assert!(script.contains("✅ rusky > hook completed"));
+        assert!(script.contains("exec \"$rusky\" run pre-commit \"$@\""));
+        assert!(script.contains("RUSKY_BIN"));
+    }
+
+    #[test]
+    fn test_installed_rusky_bin() {
+        let script = HookManager::generate_hook_script("pre-commit");
+        let expected = std::env::current_exe()
+            .unwrap()
+            .to_string_lossy()
+            .into_owned();
+        assert_eq!(HookManager::installed_rusky_bin(&script), Some(expected));
+
+        let quoted = format!("  rusky={}\n", shell_quote("/opt/it's/rusky"));
+        assert_eq!(
+            HookManager::installed_rusky_bin(&quoted),
+            Some("/opt/it's/rusky".to_string())
+        );
+        assert_eq!(
+            HookManager::installed_rusky_bin("#!/bin/sh\necho hi\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_shell_quote() {
+        assert_eq!(shell_quote("/usr/bin/rusky"), "'/usr/bin/rusky'");
+        assert_eq!(shell_quote("it's"), "'it'\\''s'");
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_create_and_remove_hook_file() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -154,13 +331,13 @@ mod tests {
         // 임시 Git 저장소 설정
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
         
         // Hook 파일 생성
-        let result = HookManager::create_hook_file("pre-commit", "echo 'test hook'").await;
+        let result = HookManager::create_hook_file("pre-commit").await;
         assert!(result.is_ok());
         
         // Hook 파일 존재 확인
@@ -175,7 +352,7 @@ mod tests {
         // Hook 파일 내용 확인
         let content = tokio::fs::read_to_string(&hook_path).await.unwrap();
         assert!(content.contains("Generated by rusky"));
-        assert!(content.contains("echo 'test hook'"));
+        assert!(content.contains("run pre-commit"));
         
         // Hook 파일 삭제
         let result = HookManager::remove_hook_file("pre-commit").await;
@@ -187,6 +364,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_remove_nonexistent_hook_file() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -194,7 +372,7 @@ mod tests {
         // 임시 Git 저장소 설정
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
@@ -208,6 +386,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[serial]
     async fn test_is_rusky_hook() {
         let temp_dir = TempDir::new().unwrap();
         let original_dir = env::current_dir().unwrap();
@@ -215,13 +394,13 @@ mod tests {
         // 임시 Git 저장소 설정
         env::set_current_dir(temp_dir.path()).unwrap();
         tokio::process::Command::new("git")
-            .args(&["init"])
+            .args(["init"])
             .output()
             .await
             .unwrap();
         
         // rusky hook 생성
-        HookManager::create_hook_file("pre-commit", "echo 'test'").await.unwrap();
+        HookManager::create_hook_file("pre-commit").await.unwrap();
         let is_rusky = HookManager::is_rusky_hook("pre-commit").await.unwrap();
         assert!(is_rusky);
         
@@ -238,4 +417,149 @@ mod tests {
         // 원래 디렉토리로 복원
         env::set_current_dir(original_dir).unwrap();
     }
+
+    #[test]
+    fn test_same_hooks_path() {
+        assert!(same_hooks_path(".rusky/_", "./.rusky/_/"));
+        assert!(!same_hooks_path(".husky/_", ".rusky/_"));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_prepare_hooks_dir_sets_core_hooks_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+        
+        let config = Config {
+            hooks_path: Some(".rusky/_".to_string()),
+            ..Config::default()
+        };
+        HookManager::prepare_hooks_dir(&config, false).await.unwrap();
+        assert_eq!(
+            Git::get_config("core.hooksPath").await.unwrap(),
+            Some(".rusky/_".to_string())
+        );
+        
+        HookManager::create_hook_file("pre-commit").await.unwrap();
+        assert!(std::path::Path::new(".rusky/_/pre-commit").exists());
+        assert!(std::path::Path::new(".rusky/_/.gitignore").exists());
+        
+        HookManager::remove_hook_file("pre-commit").await.unwrap();
+        HookManager::release_hooks_path(&config).await.unwrap();
+        assert_eq!(Git::get_config("core.hooksPath").await.unwrap(), None);
+        assert!(!std::path::Path::new(".rusky/_").exists());
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_prepare_hooks_dir_respects_foreign_hooks_path() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+        Git::set_config("core.hooksPath", ".husky/_").await.unwrap();
+        
+        // 다른 도구의 core.hooksPath는 force 없이 바꾸지 않음
+        let config = Config {
+            hooks_path: Some(".rusky/_".to_string()),
+            ..Config::default()
+        };
+        let result = HookManager::prepare_hooks_dir(&config, false).await;
+        assert!(result.unwrap_err().to_string().contains("core.hooksPath is already set"));
+        assert_eq!(
+            Git::get_config("core.hooksPath").await.unwrap(),
+            Some(".husky/_".to_string())
+        );
+        
+        // 기본 모드에서는 git이 실제로 읽는 디렉토리에 설치
+        HookManager::prepare_hooks_dir(&Config::default(), false).await.unwrap();
+        HookManager::create_hook_file("pre-commit").await.unwrap();
+        assert!(std::path::Path::new(".husky/_/pre-commit").exists());
+        
+        HookManager::prepare_hooks_dir(&config, true).await.unwrap();
+        assert_eq!(
+            Git::get_config("core.hooksPath").await.unwrap(),
+            Some(".rusky/_".to_string())
+        );
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_install_backs_up_and_uninstall_restores_foreign_hook() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+        
+        // 직접 작성한 hook
+        let hook_path = Git::get_hook_path("pre-commit").await.unwrap();
+        tokio::fs::write(&hook_path, "#!/bin/sh\necho 'manual hook'\n").await.unwrap();
+        let mut perms = tokio::fs::metadata(&hook_path).await.unwrap().permissions();
+        perms.set_mode(0o755);
+        tokio::fs::set_permissions(&hook_path, perms).await.unwrap();
+        
+        HookManager::create_hook_file("pre-commit").await.unwrap();
+        assert!(HookManager::is_rusky_hook("pre-commit").await.unwrap());
+        
+        let backup_path = HookManager::get_backup_path("pre-commit").await.unwrap();
+        let backup = tokio::fs::read_to_string(&backup_path).await.unwrap();
+        assert!(backup.contains("manual hook"));
+        let mode = tokio::fs::metadata(&backup_path).await.unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        
+        // 다시 설치해도 백업은 덮어쓰지 않음
+        HookManager::create_hook_file("pre-commit").await.unwrap();
+        let backup = tokio::fs::read_to_string(&backup_path).await.unwrap();
+        assert!(backup.contains("manual hook"));
+        
+        HookManager::remove_hook_file("pre-commit").await.unwrap();
+        let restored = tokio::fs::read_to_string(&hook_path).await.unwrap();
+        assert!(restored.contains("manual hook"));
+        assert!(!backup_path.exists());
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_remove_hook_file_keeps_foreign_hook() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+        
+        let hook_path = Git::get_hook_path("pre-push").await.unwrap();
+        tokio::fs::write(&hook_path, "#!/bin/sh\necho 'manual hook'\n").await.unwrap();
+        
+        HookManager::remove_hook_file("pre-push").await.unwrap();
+        assert!(hook_path.exists());
+        
+        env::set_current_dir(original_dir).unwrap();
+    }
 }
@@ -0,0 +1,422 @@
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+use std::path::Path;
+use tokio::fs;
+
+use super::{check_package_json, read_package_json, remove_generated_hooks, Migration};
+use crate::cli::is_valid_hook_name;
+use crate::git::Git;
+
+/// husky 설정 가져오기 (v4 `package.json`, v8/v9 `.husky/` 디렉토리)
+pub struct Husky;
+
+/// husky v4가 hook 인자를 넘기던 환경 변수
+const HUSKY_GIT_PARAMS: &str = "HUSKY_GIT_PARAMS";
+
+/// husky v4 설정을 담을 수 있는 JSON 파일
+const HUSKY_V4_FILES: &[&str] = &[".huskyrc", ".huskyrc.json"];
+
+/// rusky가 읽을 수 없는 husky v4 설정 파일
+const HUSKY_V4_SCRIPT_FILES: &[&str] = &[".huskyrc.js", "husky.config.js", ".huskyrc.cjs"];
+
+impl Husky {
+    /// 현재 디렉토리 (저장소 루트)의 husky 설정 읽기
+    pub async fn read() -> Result<Migration> {
+        let mut migration = Migration::default();
+        let mut found = false;
+
+        if Path::new(".husky").is_dir() {
+            found = true;
+            Self::read_directory(Path::new(".husky"), &mut migration).await?;
+        }
+
+        let package = read_package_json().await?;
+        if let Some(hooks) = package
+            .as_ref()
+            .and_then(|package| package.pointer("/husky/hooks"))
+            .and_then(Value::as_object)
+        {
+            found = true;
+            Self::read_v4(hooks, "package.json", &mut migration);
+        }
+        for file in HUSKY_V4_FILES {
+            if !Path::new(file).is_file() {
+                continue;
+            }
+            let content = fs::read_to_string(file).await?;
+            let config: Value = serde_json::from_str(&content)
+                .map_err(|e| anyhow!("Failed to parse {}: {}", file, e))?;
+            if let Some(hooks) = config.get("hooks").and_then(Value::as_object) {
+                found = true;
+                Self::read_v4(hooks, file, &mut migration);
+            }
+        }
+        for file in HUSKY_V4_SCRIPT_FILES {
+            if Path::new(file).is_file() {
+                found = true;
+                migration.note(format!(
+                    "{} is JavaScript and was not imported; add its hooks with 'rusky add'",
+                    file
+                ));
+            }
+        }
+
+        if !found {
+            return Err(anyhow!(
+                "No husky configuration found (.husky/ or \"husky\" in package.json)"
+            ));
+        }
+
+        if let Some(package) = &package {
+            check_package_json(package, "husky", &mut migration);
+        }
+
+        Ok(migration)
+    }
+
+    /// v8/v9의 `.husky/<hook>` 스크립트 읽기
+    async fn read_directory(dir: &Path, migration: &mut Migration) -> Result<()> {
+        let mut entries = fs::read_dir(dir).await?;
+        let mut files = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            // `_`는 husky가 만든 디렉토리
+            if entry.file_type().await?.is_file() && !name.starts_with('.') {
+                files.push(name);
+            }
+        }
+        files.sort();
+
+        for name in files {
+            if !is_valid_hook_name(&name) {
+                migration.note(format!(
+                    ".husky/{} is not a git hook and was not imported; check whether your hooks use it",
+                    name
+                ));
+                continue;
+            }
+
+            let script = fs::read_to_string(dir.join(&name)).await?;
+            let (runs, note) = convert_script(&script);
+            if let Some(note) = note {
+                migration.note(format!("{}: {}", name, note));
+            }
+            if !runs.is_empty() {
+                migration.add(&name, runs);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// v4의 `"hooks": { "pre-commit": "..." }` 읽기
+    fn read_v4(hooks: &Map<String, Value>, source: &str, migration: &mut Migration) {
+        for (hook_name, command) in hooks {
+            let Some(command) = command.as_str() else {
+                migration.note(format!(
+                    "{} in {} is not a string and was not imported",
+                    hook_name, source
+                ));
+                continue;
+            };
+            if !is_valid_hook_name(hook_name) {
+                migration.note(format!(
+                    "{} in {} is not a git hook and was not imported",
+                    hook_name, source
+                ));
+                continue;
+            }
+
+            let (run, note) = convert_v4_command(command);
+            if let Some(note) = note {
+                migration.note(format!("{}: {}", hook_name, note));
+            }
+            migration.add(hook_name, vec![run]);
+        }
+    }
+
+    /// husky가 남긴 설치 상태 정리 후 한 일을 반환
+    ///
+    /// husky가 설정한 `core.hooksPath`를 해제하고, v4가 `.git/hooks`에 만든 hook을 지웁니다.
+    pub async fn release() -> Result<Vec<String>> {
+        let mut done = Vec::new();
+
+        if let Some(hooks_path) = Git::get_config("core.hooksPath").await? {
+            let normalized = hooks_path.trim_start_matches("./").trim_end_matches('/');
+            if normalized == ".husky" || normalized.starts_with(".husky/") {
+                Git::unset_config("core.hooksPath").await?;
+                done.push(format!("Unset core.hooksPath ({})", hooks_path));
+            }
+        }
+
+        done.extend(remove_generated_hooks("husky", is_husky_v4_hook).await?);
+
+        Ok(done)
+    }
+}
+
+/// husky v4가 `.git/hooks`에 설치한 hook인지 확인
+fn is_husky_v4_hook(content: &str) -> bool {
+    content.lines().take(5).any(|line| {
+        let line = line.trim();
+        line == "# husky" || line.contains("Hook created by Husky")
+    })
+}
+
+/// v8/v9 hook 스크립트를 단계로 변환
+///
+/// 한 줄짜리 명령어만 있으면 줄마다 단계를 만들고, 셸 문법(조건문, 변수, 여러 줄 명령어)이
+/// 있으면 스크립트 전체를 한 단계로 가져옵니다.
+fn convert_script(script: &str) -> (Vec<String>, Option<String>) {
+    let lines: Vec<&str> = script
+        .lines()
+        .filter(|line| !line.starts_with("#!") && !is_husky_loader(line))
+        .collect();
+    let commands: Vec<&str> = lines
+        .iter()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    if commands.is_empty() {
+        return (
+            Vec::new(),
+            Some("the script has no commands and was not imported".to_string()),
+        );
+    }
+
+    if commands.iter().all(|command| is_simple_command(command)) {
+        return (
+            commands.iter().map(|command| command.to_string()).collect(),
+            None,
+        );
+    }
+
+    let body = lines.join("\n").trim().to_string();
+    (
+        vec![body],
+        Some(
+            "shell script imported as a single step; check that it runs without husky".to_string(),
+        ),
+    )
+}
+
+/// husky 자신을 불러오는 줄 (`. "$(dirname -- "$0")/_/husky.sh"`)
+fn is_husky_loader(line: &str) -> bool {
+    let line = line.trim();
+    (line.starts_with(". ") || line.starts_with("source "))
+        && (line.contains("husky.sh") || line.contains("/_/h\""))
+}
+
+/// 다른 줄과 상관없이 따로 실행할 수 있는 한 줄 명령어인지 확인
+fn is_simple_command(command: &str) -> bool {
+    const SHELL_KEYWORDS: &[&str] = &[
+        "if", "then", "else", "elif", "fi", "for", "while", "until", "do", "done", "case", "esac",
+        "function", "{", "}", "export", "cd", ".", "source", "exec", "exit", "set", "unset",
+        "local", "return", "trap",
+    ];
+
+    let first = command.split_whitespace().next().unwrap_or_default();
+    // `FOO=bar`처럼 다음 줄에 영향을 주는 변수 할당
+    let is_assignment = first.contains('=') && !first.starts_with('-') && !first.starts_with('=');
+
+    !SHELL_KEYWORDS.contains(&first)
+        && !is_assignment
+        && !command.ends_with('\\')
+        && !command.contains("<<")
+        && !command.contains("()")
+}
+
+/// v4 명령어의 `HUSKY_GIT_PARAMS`를 hook 인자로 바꾸기
+fn convert_v4_command(command: &str) -> (String, Option<String>) {
+    let mut run = command
+        .replace(&format!("-E {}", HUSKY_GIT_PARAMS), "--edit \"$1\"")
+        .replace(&format!("--env {}", HUSKY_GIT_PARAMS), "--edit \"$1\"")
+        .replace(&format!("${{{}}}", HUSKY_GIT_PARAMS), "$*")
+        .replace(&format!("${}", HUSKY_GIT_PARAMS), "$*");
+
+    let mut notes = Vec::new();
+    if run.contains(HUSKY_GIT_PARAMS) {
+        notes.push(format!("{} could not be converted", HUSKY_GIT_PARAMS));
+    }
+    if run.contains("HUSKY_GIT_STDIN") {
+        notes.push(
+            "HUSKY_GIT_STDIN is not set by rusky; steps read git's stdin directly".to_string(),
+        );
+    }
+    if run.trim().is_empty() {
+        run = "true".to_string();
+    }
+
+    let note = (!notes.is_empty()).then(|| notes.join("; "));
+    (run, note)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_convert_v8_and_v9_scripts() {
+        let v8 = "#!/usr/bin/env sh\n. \"$(dirname -- \"$0\")/_/husky.sh\"\n\nnpx lint-staged\nnpm test\n";
+        assert_eq!(
+            convert_script(v8),
+            (
+                vec!["npx lint-staged".to_string(), "npm test".to_string()],
+                None
+            )
+        );
+
+        let v9 = "# run commitlint\nnpx --no -- commitlint --edit $1\n";
+        assert_eq!(
+            convert_script(v9).0,
+            vec!["npx --no -- commitlint --edit $1".to_string()]
+        );
+
+        assert_eq!(convert_script("#!/bin/sh\n\n").0, Vec::<String>::new());
+        assert!(convert_script("#!/bin/sh\n\n").1.is_some());
+    }
+
+    #[test]
+    fn test_convert_shell_script_as_one_step() {
+        let script = "#!/bin/sh\n. \"$(dirname \"$0\")/_/husky.sh\"\n\nif [ -n \"$CI\" ]; then\n  exit 0\nfi\nnpm test\n";
+        let (runs, note) = convert_script(script);
+        assert_eq!(
+            runs,
+            vec!["if [ -n \"$CI\" ]; then\n  exit 0\nfi\nnpm test".to_string()]
+        );
+        assert!(note.unwrap().contains("single step"));
+
+        assert!(!is_simple_command(
+            "export NODE_OPTIONS=--max-old-space-size=4096"
+        ));
+        assert!(!is_simple_command("FORCE_COLOR=1"));
+        assert!(!is_simple_command("exec < /dev/tty"));
+        assert!(is_simple_command("npx eslint --fix ."));
+        assert!(is_simple_command("cargo test -- --test-threads=1"));
+    }
+
+    #[test]
+    fn test_convert_v4_command() {
+        assert_eq!(
+            convert_v4_command("commitlint -E HUSKY_GIT_PARAMS"),
+            ("commitlint --edit \"$1\"".to_string(), None)
+        );
+        assert_eq!(
+            convert_v4_command("node check.js $HUSKY_GIT_PARAMS").0,
+            "node check.js $*"
+        );
+        assert!(convert_v4_command("echo $HUSKY_GIT_STDIN | x").1.is_some());
+        assert_eq!(
+            convert_v4_command("npm test"),
+            ("npm test".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_is_husky_v4_hook() {
+        assert!(is_husky_v4_hook(
+            "#!/bin/sh\n# husky\n\n# Hook created by Husky v4.3.8\n"
+        ));
+        assert!(!is_husky_v4_hook("#!/bin/sh\nnpm test\n"));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_read_husky_directory_and_package_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        fs::create_dir_all(".husky/_").await.unwrap();
+        fs::write(".husky/_/husky.sh", "").await.unwrap();
+        fs::write(".husky/.gitignore", "_\n").await.unwrap();
+        fs::write(".husky/pre-commit", "npx lint-staged\n")
+            .await
+            .unwrap();
+        fs::write(".husky/commit-msg", "npx --no -- commitlint --edit $1\n")
+            .await
+            .unwrap();
+        fs::write(".husky/common.sh", "command_exists () { true; }\n")
+            .await
+            .unwrap();
+        fs::write(
+            "package.json",
+            r#"{
+                "scripts": { "prepare": "husky" },
+                "devDependencies": { "husky": "^9.0.0" },
+                "husky": { "hooks": { "pre-push": "npm test" } }
+            }"#,
+        )
+        .await
+        .unwrap();
+
+        let migration = Husky::read().await.unwrap();
+        assert_eq!(migration.runs("pre-commit"), vec!["npx lint-staged"]);
+        assert_eq!(
+            migration.runs("commit-msg"),
+            vec!["npx --no -- commitlint --edit $1"]
+        );
+        assert_eq!(migration.runs("pre-push"), vec!["npm test"]);
+
+        let notes = migration.notes.join("\n");
+        assert!(notes.contains(".husky/common.sh is not a git hook"));
+        assert!(notes.contains("scripts.prepare still runs husky"));
+        assert!(notes.contains("remove husky from devDependencies"));
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_read_without_husky() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        fs::write("package.json", "{}").await.unwrap();
+        assert!(Husky::read().await.is_err());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_release_husky() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init", "-q"])
+            .output()
+            .await
+            .unwrap();
+
+        Git::set_config("core.hooksPath", ".husky/_").await.unwrap();
+        let done = Husky::release().await.unwrap();
+        assert_eq!(done, vec!["Unset core.hooksPath (.husky/_)".to_string()]);
+        assert_eq!(Git::get_config("core.hooksPath").await.unwrap(), None);
+
+        // husky v4가 설치한 hook만 지움
+        fs::write(".git/hooks/pre-commit", "#!/bin/sh\n# husky\n")
+            .await
+            .unwrap();
+        fs::write(".git/hooks/pre-push", "#!/bin/sh\nmake check\n")
+            .await
+            .unwrap();
+        let done = Husky::release().await.unwrap();
+        assert_eq!(done, vec!["Removed husky's pre-commit hook".to_string()]);
+        assert!(Path::new(".git/hooks/pre-push").exists());
+
+        // 다른 도구의 core.hooksPath는 그대로
+        Git::set_config("core.hooksPath", ".githooks")
+            .await
+            .unwrap();
+        assert!(Husky::release().await.unwrap().is_empty());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
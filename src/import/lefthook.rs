@@ -0,0 +1,399 @@
+use anyhow::{anyhow, Result};
+use serde_yaml::{Mapping, Value};
+use std::path::Path;
+use tokio::fs;
+
+use super::{
+    check_package_json, read_package_json, shell_word, yaml_scalar, yaml_strings, Migration,
+};
+use crate::cli::is_valid_hook_name;
+use crate::config::HookCommand;
+
+const CONFIG_FILES: &[&str] = &[
+    "lefthook.yml",
+    ".lefthook.yml",
+    "lefthook.yaml",
+    ".lefthook.yaml",
+];
+const LOCAL_CONFIG_FILES: &[&str] = &[
+    "lefthook-local.yml",
+    ".lefthook-local.yml",
+    "lefthook-local.yaml",
+    ".lefthook-local.yaml",
+];
+
+/// 결과에 영향을 주지 않아 그냥 무시하는 설정
+const IGNORED_KEYS: &[&str] = &[
+    "fail_text",
+    "tags",
+    "priority",
+    "piped",
+    "follow",
+    "exclude_tags",
+];
+
+/// lefthook 설정 가져오기 (`lefthook.yml`)
+pub struct Lefthook;
+
+impl Lefthook {
+    /// 현재 디렉토리 (저장소 루트)의 lefthook 설정 읽기
+    pub async fn read() -> Result<Migration> {
+        let Some(path) = CONFIG_FILES.iter().find(|path| Path::new(path).is_file()) else {
+            return Err(anyhow!("No lefthook configuration found (lefthook.yml)"));
+        };
+
+        let content = fs::read_to_string(path).await?;
+        let config: Value = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse {}: {}", path, e))?;
+        let mut migration = Self::convert(&config);
+
+        if let Some(local) = LOCAL_CONFIG_FILES
+            .iter()
+            .find(|path| Path::new(path).is_file())
+        {
+            migration.note(format!(
+                "{} was not imported; move your local overrides by hand",
+                local
+            ));
+        }
+        if let Some(package) = read_package_json().await? {
+            check_package_json(&package, "lefthook", &mut migration);
+        }
+
+        Ok(migration)
+    }
+
+    fn convert(config: &Value) -> Migration {
+        let mut migration = Migration::default();
+        let Some(config) = config.as_mapping() else {
+            migration.note("lefthook configuration is empty");
+            return migration;
+        };
+
+        for (key, value) in config {
+            let Some(key) = key.as_str() else { continue };
+            match key {
+                "extends" | "remotes" => migration.note(format!(
+                    "'{}' was not imported; copy the shared hooks into .rusky/config.json by hand",
+                    key
+                )),
+                key if is_valid_hook_name(key) => Self::convert_hook(key, value, &mut migration),
+                // min_version, colors, output 같은 도구 설정
+                _ => {}
+            }
+        }
+
+        migration
+    }
+
+    fn convert_hook(hook_name: &str, value: &Value, migration: &mut Migration) {
+        let Some(hook) = value.as_mapping() else {
+            return;
+        };
+
+        for (key, value) in hook {
+            let Some(key) = key.as_str() else { continue };
+            match key {
+                "parallel" => {
+                    migration.hook_mut(hook_name).parallel = value.as_bool() == Some(true)
+                }
+                "commands" => {
+                    let mut commands: Vec<(&str, &Mapping)> = value
+                        .as_mapping()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|(name, command)| Some((name.as_str()?, command.as_mapping()?)))
+                        .collect();
+                    // lefthook은 priority가 낮은 명령어부터 실행
+                    commands.sort_by_key(|(_, command)| priority(command));
+                    for (name, command) in commands {
+                        Self::convert_command(hook_name, name, command, migration);
+                    }
+                }
+                "jobs" => {
+                    for job in value.as_sequence().into_iter().flatten() {
+                        let Some(job) = job.as_mapping() else {
+                            continue;
+                        };
+                        let name = job.get("name").and_then(Value::as_str).unwrap_or_default();
+                        if job.contains_key("group") {
+                            migration.note(format!(
+                                "{}: job groups are not supported; add the steps by hand",
+                                hook_name
+                            ));
+                        } else if let Some(script) = job.get("script").and_then(Value::as_str) {
+                            Self::convert_script(hook_name, script, job, migration);
+                        } else {
+                            Self::convert_command(hook_name, name, job, migration);
+                        }
+                    }
+                }
+                "scripts" => {
+                    for (name, script) in value.as_mapping().into_iter().flatten() {
+                        if let (Some(name), Some(script)) = (name.as_str(), script.as_mapping()) {
+                            Self::convert_script(hook_name, name, script, migration);
+                        }
+                    }
+                }
+                key if IGNORED_KEYS.contains(&key) => {}
+                key => migration.note(format!("{}: '{}' is not supported", hook_name, key)),
+            }
+        }
+    }
+
+    /// `run`이 있는 command 또는 job을 단계로 변환
+    fn convert_command(hook_name: &str, name: &str, command: &Mapping, migration: &mut Migration) {
+        let Some(run) = command.get("run").and_then(Value::as_str) else {
+            migration.note(format!(
+                "{} {}: no 'run' command, not imported",
+                hook_name, name
+            ));
+            return;
+        };
+        let label = if name.is_empty() { run } else { name };
+
+        let (run, wants_files) = convert_placeholders(run.trim(), label, migration);
+        let mut step = HookCommand::new(name.to_string(), run);
+
+        let globs = command.get("glob").map(yaml_strings).unwrap_or_default();
+        if wants_files {
+            step.glob = if globs.is_empty() {
+                vec!["*".to_string()]
+            } else {
+                globs
+            };
+        } else if !globs.is_empty() {
+            migration.note(format!(
+                "{}: 'glob' without {{staged_files}} is not supported; the step always runs",
+                label
+            ));
+        }
+
+        Self::apply_options(hook_name, label, &mut step, command, migration);
+        migration.add_step(hook_name, step);
+    }
+
+    /// `.lefthook/<hook>/<script>` 스크립트를 단계로 변환
+    fn convert_script(hook_name: &str, name: &str, script: &Mapping, migration: &mut Migration) {
+        let path = format!(".lefthook/{}/{}", hook_name, name);
+        let run = match script.get("runner").and_then(Value::as_str) {
+            Some(runner) => format!("{} {} \"$@\"", runner, shell_word(&path)),
+            None => format!("{} \"$@\"", shell_word(&path)),
+        };
+
+        let mut step = HookCommand::new(name.to_string(), run);
+        Self::apply_options(hook_name, name, &mut step, script, migration);
+        migration.add_step(hook_name, step);
+    }
+
+    /// `env`, `stage_fixed`와 지원하지 않는 설정 처리
+    fn apply_options(
+        hook_name: &str,
+        label: &str,
+        step: &mut HookCommand,
+        command: &Mapping,
+        migration: &mut Migration,
+    ) {
+        for (key, value) in command {
+            let Some(key) = key.as_str() else { continue };
+            match key {
+                "name" | "run" | "glob" | "script" | "runner" => {}
+                "env" => {
+                    let mut assignments = String::new();
+                    for (name, value) in value.as_mapping().into_iter().flatten() {
+                        if let (Some(name), Some(value)) = (name.as_str(), yaml_scalar(value)) {
+                            assignments.push_str(&format!("{}={} ", name, shell_word(&value)));
+                        }
+                    }
+                    step.run.insert_str(0, &assignments);
+                }
+                "stage_fixed" if value.as_bool() == Some(true) => {
+                    migration.hook_mut(hook_name).stash = true
+                }
+                "stage_fixed" => {}
+                key if IGNORED_KEYS.contains(&key) => {}
+                key => migration.note(format!("{}: '{}' is not supported", label, key)),
+            }
+        }
+    }
+
+    /// lefthook이 설치한 hook인지 확인
+    pub fn is_generated_hook(content: &str) -> bool {
+        content.contains("call_lefthook") || content.contains("lefthook run")
+    }
+}
+
+fn priority(command: &Mapping) -> u64 {
+    command.get("priority").and_then(Value::as_u64).unwrap_or(0)
+}
+
+/// lefthook 템플릿을 셸 인자로 바꾸고 staged 파일이 필요한지 반환
+///
+/// `{staged_files}`는 지우고 대신 glob으로 고른 파일을 명령어 끝에 붙입니다.
+fn convert_placeholders(run: &str, label: &str, migration: &mut Migration) -> (String, bool) {
+    let mut run = run.to_string();
+    let mut wants_files = false;
+
+    for placeholder in ["{staged_files}", "{files}"] {
+        if !run.contains(placeholder) {
+            continue;
+        }
+        if placeholder == "{files}" {
+            migration.note(format!(
+                "{}: {{files}} was replaced by the staged files",
+                label
+            ));
+        }
+        if !run.trim_end().ends_with(placeholder) {
+            migration.note(format!(
+                "{}: staged files are now passed at the end of the command",
+                label
+            ));
+        }
+        run = run.replace(placeholder, "");
+        wants_files = true;
+    }
+
+    for placeholder in ["{all_files}", "{push_files}"] {
+        if run.contains(placeholder) {
+            migration.note(format!("{}: {} is not supported", label, placeholder));
+        }
+    }
+
+    run = run.replace("{0}", "\"$@\"");
+    for index in 1..=9 {
+        run = run.replace(&format!("{{{}}}", index), &format!("\"${}\"", index));
+    }
+
+    let run = run.split_whitespace().collect::<Vec<_>>().join(" ");
+    (run, wants_files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(yaml: &str) -> Migration {
+        Lefthook::convert(&serde_yaml::from_str(yaml).unwrap())
+    }
+
+    #[test]
+    fn test_convert_commands() {
+        let migration = convert(
+            r#"
+min_version: 1.5.0
+pre-commit:
+  parallel: true
+  commands:
+    lint:
+      glob: "*.{js,ts}"
+      run: npx eslint --fix {staged_files}
+      stage_fixed: true
+    types:
+      priority: 1
+      run: npx tsc --noEmit
+    format:
+      priority: 2
+      env:
+        NODE_ENV: test
+      run: npx prettier --check {staged_files}
+commit-msg:
+  commands:
+    commitlint:
+      run: npx commitlint --edit {1}
+"#,
+        );
+
+        let (name, hook) = &migration.hooks[0];
+        assert_eq!(name, "pre-commit");
+        assert!(hook.parallel && hook.stash);
+
+        let steps: Vec<(&str, &str)> = hook
+            .commands
+            .iter()
+            .map(|step| (step.name.as_str(), step.run.as_str()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                ("lint", "npx eslint --fix"),
+                ("types", "npx tsc --noEmit"),
+                ("format", "NODE_ENV=test npx prettier --check"),
+            ]
+        );
+        assert_eq!(hook.commands[0].glob, vec!["*.{js,ts}"]);
+        assert!(hook.commands[1].glob.is_empty());
+        assert_eq!(hook.commands[2].glob, vec!["*"]);
+
+        assert_eq!(
+            migration.runs("commit-msg"),
+            vec!["npx commitlint --edit \"$1\""]
+        );
+        assert!(migration.notes.is_empty());
+    }
+
+    #[test]
+    fn test_convert_jobs_and_scripts() {
+        let migration = convert(
+            r#"
+pre-push:
+  jobs:
+    - name: test
+      run: cargo test
+    - script: check.sh
+      runner: bash
+  scripts:
+    "audit.js":
+      runner: node
+"#,
+        );
+
+        assert_eq!(
+            migration.runs("pre-push"),
+            vec![
+                "cargo test",
+                "bash .lefthook/pre-push/check.sh \"$@\"",
+                "node .lefthook/pre-push/audit.js \"$@\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_settings_are_noted() {
+        let migration = convert(
+            r#"
+extends:
+  - shared/lefthook.yml
+pre-commit:
+  skip:
+    - merge
+  commands:
+    lint:
+      root: frontend/
+      glob: "*.js"
+      run: npm run lint
+    check:
+      run: ./check {all_files}
+"#,
+        );
+
+        assert_eq!(
+            migration.runs("pre-commit"),
+            vec!["npm run lint", "./check {all_files}"]
+        );
+        let notes = migration.notes.join("\n");
+        assert!(notes.contains("'extends' was not imported"));
+        assert!(notes.contains("pre-commit: 'skip' is not supported"));
+        assert!(notes.contains("lint: 'root' is not supported"));
+        assert!(notes.contains("lint: 'glob' without {staged_files}"));
+        assert!(notes.contains("check: {all_files} is not supported"));
+    }
+
+    #[test]
+    fn test_is_generated_hook() {
+        assert!(Lefthook::is_generated_hook(
+            "#!/bin/sh\ncall_lefthook run \"pre-commit\" \"$@\"\n"
+        ));
+        assert!(!Lefthook::is_generated_hook("#!/bin/sh\nnpm test\n"));
+    }
+}
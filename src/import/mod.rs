@@ -0,0 +1,280 @@
+//! 다른 hook 관리 도구의 설정을 rusky 설정으로 가져오기
+
+mod husky;
+mod lefthook;
+mod pre_commit;
+mod simple_git_hooks;
+
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+use serde_json::Value;
+use std::path::Path;
+use tokio::fs;
+
+use crate::cli::is_valid_hook_name;
+use crate::config::{HookCommand, HookConfig};
+use crate::git::Git;
+use crate::hooks::shell_quote;
+
+pub use husky::Husky;
+pub use lefthook::Lefthook;
+pub use pre_commit::PreCommit;
+pub use simple_git_hooks::SimpleGitHooks;
+
+/// 설정을 가져올 수 있는 도구
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum Tool {
+    Husky,
+    PreCommit,
+    Lefthook,
+    SimpleGitHooks,
+}
+
+impl Tool {
+    /// 현재 디렉토리 (저장소 루트)의 설정 읽기
+    pub async fn read(self) -> Result<Migration> {
+        match self {
+            Tool::Husky => Husky::read().await,
+            Tool::PreCommit => PreCommit::read().await,
+            Tool::Lefthook => Lefthook::read().await,
+            Tool::SimpleGitHooks => SimpleGitHooks::read().await,
+        }
+    }
+
+    /// 도구가 남긴 설치 상태 정리 후 한 일을 반환
+    pub async fn release(self) -> Result<Vec<String>> {
+        match self {
+            Tool::Husky => Husky::release().await,
+            Tool::PreCommit => remove_generated_hooks("pre-commit", PreCommit::is_generated_hook).await,
+            Tool::Lefthook => remove_generated_hooks("lefthook", Lefthook::is_generated_hook).await,
+            Tool::SimpleGitHooks => {
+                remove_generated_hooks("simple-git-hooks", SimpleGitHooks::is_generated_hook).await
+            }
+        }
+    }
+}
+
+/// 가져온 hook 설정과 직접 확인해야 할 내용
+#[derive(Debug, Default)]
+pub struct Migration {
+    /// hook 이름과 가져온 설정 (원래 순서대로)
+    pub hooks: Vec<(String, HookConfig)>,
+    /// 자동으로 옮기지 못해 사용자가 확인해야 하는 내용
+    pub notes: Vec<String>,
+}
+
+impl Migration {
+    /// hook 설정 가져오기 (없으면 추가)
+    fn hook_mut(&mut self, hook_name: &str) -> &mut HookConfig {
+        let index = match self.hooks.iter().position(|(name, _)| name == hook_name) {
+            Some(index) => index,
+            None => {
+                self.hooks.push((hook_name.to_string(), HookConfig::default()));
+                self.hooks.len() - 1
+            }
+        };
+        &mut self.hooks[index].1
+    }
+
+    /// 명령어마다 이름 없는 단계 추가
+    fn add(&mut self, hook_name: &str, runs: Vec<String>) {
+        for run in runs {
+            self.add_step(hook_name, HookCommand::new(String::new(), run));
+        }
+    }
+
+    fn add_step(&mut self, hook_name: &str, step: HookCommand) {
+        self.hook_mut(hook_name).commands.push(step);
+    }
+
+    fn note(&mut self, note: impl Into<String>) {
+        self.notes.push(note.into());
+    }
+
+    /// hook에 가져온 명령어 목록
+    #[cfg(test)]
+    fn runs(&self, hook_name: &str) -> Vec<String> {
+        self.hooks
+            .iter()
+            .filter(|(name, _)| name == hook_name)
+            .flat_map(|(_, hook)| hook.commands.iter().map(|step| step.run.clone()))
+            .collect()
+    }
+}
+
+async fn read_package_json() -> Result<Option<Value>> {
+    if !Path::new("package.json").is_file() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string("package.json").await?;
+    let package = serde_json::from_str(&content)
+        .map_err(|e| anyhow!("Failed to parse package.json: {}", e))?;
+    Ok(Some(package))
+}
+
+/// `package.json`에 남은 도구의 설치 스크립트와 의존성 안내
+fn check_package_json(package: &Value, tool: &str, migration: &mut Migration) {
+    if let Some(scripts) = package.get("scripts").and_then(Value::as_object) {
+        for (name, script) in scripts {
+            if script.as_str().is_some_and(|script| script.contains(tool)) {
+                migration.note(format!(
+                    "package.json scripts.{} still runs {}; replace it with 'rusky install'",
+                    name, tool
+                ));
+            }
+        }
+    }
+
+    for dependencies in ["dependencies", "devDependencies"] {
+        if package.pointer(&format!("/{}/{}", dependencies, tool)).is_some() {
+            migration.note(format!("remove {} from {} in package.json", tool, dependencies));
+        }
+    }
+}
+
+/// hooks 디렉토리에서 도구가 만든 hook 파일 지우기
+async fn remove_generated_hooks(tool: &str, is_generated: fn(&str) -> bool) -> Result<Vec<String>> {
+    let mut done = Vec::new();
+    let hooks_dir = Git::get_hooks_dir().await?;
+    if !hooks_dir.is_dir() {
+        return Ok(done);
+    }
+
+    let mut names = Vec::new();
+    let mut entries = fs::read_dir(&hooks_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if is_valid_hook_name(&name) {
+            names.push(name);
+        }
+    }
+    names.sort();
+
+    for name in names {
+        let path = hooks_dir.join(&name);
+        let content = fs::read_to_string(&path).await.unwrap_or_default();
+        if is_generated(&content) {
+            fs::remove_file(&path).await?;
+            done.push(format!("Removed {}'s {} hook", tool, name));
+        }
+    }
+
+    Ok(done)
+}
+
+/// 셸 인자로 쓸 문자열 (특수 문자가 있을 때만 따옴표로 감싸기)
+fn shell_word(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,@+%".contains(c));
+    if plain {
+        value.to_string()
+    } else {
+        shell_quote(value)
+    }
+}
+
+/// YAML 스칼라 값을 문자열로 (`120`, `true`도 허용)
+fn yaml_scalar(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(value) => Some(value.clone()),
+        serde_yaml::Value::Number(value) => Some(value.to_string()),
+        serde_yaml::Value::Bool(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// `"*.js"`와 `["*.js", "*.ts"]` 두 가지 표기의 glob 목록
+fn yaml_strings(value: &serde_yaml::Value) -> Vec<String> {
+    match value {
+        serde_yaml::Value::Sequence(values) => values.iter().filter_map(yaml_scalar).collect(),
+        value => yaml_scalar(value).into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_migration_merges_hooks() {
+        let mut migration = Migration::default();
+        migration.add("pre-commit", vec!["npm run lint".to_string()]);
+        migration.add("pre-push", vec!["npm test".to_string()]);
+        migration.add("pre-commit", vec!["npm run format".to_string()]);
+
+        let hooks: Vec<(&str, usize)> = migration
+            .hooks
+            .iter()
+            .map(|(name, hook)| (name.as_str(), hook.commands.len()))
+            .collect();
+        assert_eq!(hooks, vec![("pre-commit", 2), ("pre-push", 1)]);
+    }
+
+    #[test]
+    fn test_check_package_json() {
+        let package: Value = serde_json::from_str(
+            r#"{
+                "scripts": { "prepare": "lefthook install", "test": "jest" },
+                "devDependencies": { "lefthook": "^1.5.0" }
+            }"#,
+        )
+        .unwrap();
+
+        let mut migration = Migration::default();
+        check_package_json(&package, "lefthook", &mut migration);
+        assert_eq!(
+            migration.notes,
+            vec![
+                "package.json scripts.prepare still runs lefthook; replace it with 'rusky install'",
+                "remove lefthook from devDependencies in package.json",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_yaml_strings() {
+        let value: serde_yaml::Value = serde_yaml::from_str("[\"*.js\", 12]").unwrap();
+        assert_eq!(yaml_strings(&value), vec!["*.js", "12"]);
+        let value: serde_yaml::Value = serde_yaml::from_str("\"*.rs\"").unwrap();
+        assert_eq!(yaml_strings(&value), vec!["*.rs"]);
+    }
+
+    #[test]
+    fn test_shell_word() {
+        assert_eq!(shell_word("--maxkb=500"), "--maxkb=500");
+        assert_eq!(shell_word("src/*.rs"), "'src/*.rs'");
+        assert_eq!(shell_word("it's"), "'it'\\''s'");
+        assert_eq!(shell_word(""), "''");
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_remove_generated_hooks() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init", "-q"])
+            .output()
+            .await
+            .unwrap();
+
+        fs::write(".git/hooks/pre-commit", "#!/bin/sh\n# File generated by pre-commit\n")
+            .await
+            .unwrap();
+        fs::write(".git/hooks/pre-push", "#!/bin/sh\nmake check\n").await.unwrap();
+
+        let done = Tool::PreCommit.release().await.unwrap();
+        assert_eq!(done, vec!["Removed pre-commit's pre-commit hook".to_string()]);
+        assert!(!Path::new(".git/hooks/pre-commit").exists());
+        assert!(Path::new(".git/hooks/pre-push").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
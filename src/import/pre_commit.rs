@@ -0,0 +1,675 @@
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::path::Path;
+use tokio::fs;
+
+use super::{shell_word, yaml_scalar, Migration};
+use crate::checks::{Builtin, Format, HygieneRules, LargeFilesRules, SecretsRules, SyntaxRules};
+use crate::cli::is_valid_hook_name;
+use crate::config::HookCommand;
+
+const CONFIG_FILE: &str = ".pre-commit-config.yaml";
+
+/// 내장 검사로 바꿀 수 있는 훅이 있는 저장소
+const PRE_COMMIT_HOOKS_REPO: &str = "pre-commit/pre-commit-hooks";
+
+/// pre-commit의 파일 type과 같은 파일을 고르는 glob
+const TYPE_GLOBS: &[(&str, &[&str])] = &[
+    ("python", &["*.py", "*.pyi"]),
+    ("pyi", &["*.pyi"]),
+    ("javascript", &["*.js", "*.jsx", "*.mjs", "*.cjs"]),
+    ("jsx", &["*.jsx"]),
+    ("ts", &["*.ts", "*.mts", "*.cts"]),
+    ("tsx", &["*.tsx"]),
+    ("rust", &["*.rs"]),
+    ("go", &["*.go"]),
+    ("json", &["*.json"]),
+    ("yaml", &["*.yaml", "*.yml"]),
+    ("toml", &["*.toml"]),
+    ("markdown", &["*.md", "*.markdown"]),
+    ("shell", &["*.sh", "*.bash"]),
+    ("bash", &["*.bash", "*.sh"]),
+    ("css", &["*.css"]),
+    ("scss", &["*.scss"]),
+    ("html", &["*.html", "*.htm"]),
+    ("java", &["*.java"]),
+    ("kotlin", &["*.kt", "*.kts"]),
+    ("ruby", &["*.rb"]),
+    ("php", &["*.php"]),
+    ("c", &["*.c", "*.h"]),
+    ("c++", &["*.cpp", "*.cc", "*.cxx", "*.hpp", "*.hh"]),
+    ("swift", &["*.swift"]),
+    ("sql", &["*.sql"]),
+    ("vue", &["*.vue"]),
+    ("svelte", &["*.svelte"]),
+    ("terraform", &["*.tf", "*.tfvars"]),
+    ("proto", &["*.proto"]),
+    ("dockerfile", &["Dockerfile", "*.dockerfile"]),
+];
+
+/// 파일을 고르지 않는 (모든 파일에 해당하는) type
+const GENERIC_TYPES: &[&str] = &["file", "text", "executable", "non-executable"];
+
+#[derive(Debug, Deserialize)]
+struct PreCommitConfig {
+    #[serde(default)]
+    repos: Vec<Repo>,
+    default_stages: Option<Vec<String>>,
+    default_install_hook_types: Option<Vec<String>>,
+    files: Option<String>,
+    exclude: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Repo {
+    repo: String,
+    #[serde(default)]
+    hooks: Vec<PreCommitHook>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PreCommitHook {
+    id: String,
+    entry: Option<String>,
+    language: Option<String>,
+    files: Option<String>,
+    exclude: Option<String>,
+    #[serde(default)]
+    types: Vec<String>,
+    #[serde(default)]
+    types_or: Vec<String>,
+    #[serde(default)]
+    exclude_types: Vec<String>,
+    #[serde(default)]
+    args: Vec<serde_yaml::Value>,
+    pass_filenames: Option<bool>,
+    #[serde(default)]
+    always_run: bool,
+    stages: Option<Vec<String>>,
+}
+
+/// pre-commit 설정 가져오기 (`.pre-commit-config.yaml`)
+pub struct PreCommit;
+
+impl PreCommit {
+    /// 현재 디렉토리 (저장소 루트)의 `.pre-commit-config.yaml` 읽기
+    pub async fn read() -> Result<Migration> {
+        if !Path::new(CONFIG_FILE).is_file() {
+            return Err(anyhow!(
+                "No pre-commit configuration found ({})",
+                CONFIG_FILE
+            ));
+        }
+
+        let content = fs::read_to_string(CONFIG_FILE).await?;
+        let config: PreCommitConfig = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse {}: {}", CONFIG_FILE, e))?;
+
+        Ok(Self::convert(&config))
+    }
+
+    fn convert(config: &PreCommitConfig) -> Migration {
+        let mut migration = Migration::default();
+
+        if config.files.is_some() || config.exclude.is_some() {
+            migration.note(
+                "top-level 'files'/'exclude' are not supported; add the filter to each step's glob",
+            );
+        }
+
+        // `stages`가 없는 훅은 `default_stages`, 그것도 없으면 설치된 훅 종류에서 실행됨
+        let default_stages = config
+            .default_stages
+            .clone()
+            .or_else(|| config.default_install_hook_types.clone())
+            .unwrap_or_else(|| vec!["pre-commit".to_string()]);
+
+        for repo in &config.repos {
+            match repo.repo.as_str() {
+                "local" => {
+                    for hook in &repo.hooks {
+                        Self::convert_local(hook, &default_stages, &mut migration);
+                    }
+                }
+                "meta" => migration
+                    .note("meta hooks only check pre-commit's own config and were not imported"),
+                url if url.contains(PRE_COMMIT_HOOKS_REPO) => {
+                    Self::convert_pre_commit_hooks(repo, &mut migration)
+                }
+                url => {
+                    for hook in &repo.hooks {
+                        migration.note(format!(
+                            "{} from {} was not imported; pre-commit installs it from the repository, so add an equivalent command with 'rusky add'",
+                            hook.id, url
+                        ));
+                    }
+                }
+            }
+        }
+
+        migration
+    }
+
+    /// `repo: local` 훅을 단계로 변환
+    fn convert_local(hook: &PreCommitHook, default_stages: &[String], migration: &mut Migration) {
+        let Some(entry) = &hook.entry else {
+            migration.note(format!("{} has no entry and was not imported", hook.id));
+            return;
+        };
+
+        let language = hook.language.as_deref().unwrap_or("system");
+        match language {
+            "system" | "script" | "unsupported" | "unsupported_script" => {}
+            "pygrep" | "fail" => {
+                migration.note(format!(
+                    "{} uses language '{}', which rusky cannot run; it was not imported",
+                    hook.id, language
+                ));
+                return;
+            }
+            language => migration.note(format!(
+                "{} uses language '{}'; pre-commit installed its environment, so make sure '{}' is on PATH",
+                hook.id, language, entry
+            )),
+        }
+
+        let mut run = entry.clone();
+        for arg in &hook.args {
+            if let Some(arg) = yaml_scalar(arg) {
+                run.push(' ');
+                run.push_str(&shell_word(&arg));
+            }
+        }
+
+        if hook.exclude.is_some() || !hook.exclude_types.is_empty() {
+            migration.note(format!(
+                "{}: 'exclude' and 'exclude_types' are not supported",
+                hook.id
+            ));
+        }
+        // pre-commit의 `types`는 모두 일치해야 하지만 glob으로는 하나만 표현할 수 있음
+        let specific_types: Vec<&str> = hook
+            .types
+            .iter()
+            .map(String::as_str)
+            .filter(|kind| !GENERIC_TYPES.contains(kind))
+            .collect();
+        if specific_types.len() > 1 {
+            migration.note(format!(
+                "{}: only '{}' of 'types' [{}] was imported; files had to match all of them (use 'types_or' for any)",
+                hook.id,
+                specific_types[0],
+                specific_types.join(", ")
+            ));
+        }
+        if hook.always_run {
+            migration.note(format!(
+                "{}: 'always_run' is not supported; the step is skipped when no staged file matches",
+                hook.id
+            ));
+        }
+
+        let pass_filenames = hook.pass_filenames.unwrap_or(true);
+        let stages = hook.stages.as_deref().unwrap_or(default_stages);
+
+        for stage in stages {
+            let Some(hook_name) = stage_hook(stage) else {
+                migration.note(format!(
+                    "{}: stage '{}' is not supported and was not imported",
+                    hook.id, stage
+                ));
+                continue;
+            };
+
+            let mut step = HookCommand::new(hook.id.clone(), run.clone());
+            match hook_name {
+                "pre-commit" if pass_filenames => match file_globs(hook) {
+                    Some(globs) => step.glob = globs,
+                    None => {
+                        migration.note(format!(
+                            "{}: the 'files' pattern {} could not be converted to a glob; the step gets every staged file",
+                            hook.id,
+                            hook.files.as_deref().unwrap_or_default()
+                        ));
+                        step.glob = vec!["*".to_string()];
+                    }
+                },
+                "pre-commit"
+                    if hook.files.is_some()
+                        || !hook.types.is_empty()
+                        || !hook.types_or.is_empty() =>
+                {
+                    migration.note(format!(
+                        "{}: runs on every commit because 'files'/'types' only apply with pass_filenames",
+                        hook.id
+                    ))
+                }
+                // pre-commit이 메시지 파일 경로를 인자로 넘김
+                "commit-msg" | "prepare-commit-msg" if pass_filenames => {
+                    step.run.push_str(" \"$1\"");
+                }
+                hook_name if pass_filenames => migration.note(format!(
+                    "{}: changed files are not passed to {} steps",
+                    hook.id, hook_name
+                )),
+                _ => {}
+            }
+
+            migration.add_step(hook_name, step);
+        }
+    }
+
+    /// pre-commit/pre-commit-hooks의 훅을 내장 검사로 변환
+    fn convert_pre_commit_hooks(repo: &Repo, migration: &mut Migration) {
+        let mut hygiene = HygieneRules {
+            conflict_markers: false,
+            trailing_whitespace: false,
+            final_newline: false,
+            mixed_line_endings: false,
+            case_conflicts: false,
+            ..HygieneRules::default()
+        };
+        let mut formats = Vec::new();
+        let mut builtins = Vec::new();
+
+        for hook in &repo.hooks {
+            let args: Vec<String> = hook.args.iter().filter_map(yaml_scalar).collect();
+            match hook.id.as_str() {
+                "trailing-whitespace" => {
+                    hygiene.trailing_whitespace = true;
+                    hygiene.fix = true;
+                }
+                "end-of-file-fixer" => {
+                    hygiene.final_newline = true;
+                    hygiene.fix = true;
+                }
+                "mixed-line-ending" => {
+                    hygiene.mixed_line_endings = true;
+                    hygiene.fix |= !args.iter().any(|arg| arg == "--fix=no");
+                }
+                "check-merge-conflict" => hygiene.conflict_markers = true,
+                "check-case-conflict" => hygiene.case_conflicts = true,
+                "check-json" => formats.push(Format::Json),
+                "check-toml" => formats.push(Format::Toml),
+                "check-yaml" => formats.push(Format::Yaml),
+                "check-added-large-files" => {
+                    let mut rules = LargeFilesRules::default();
+                    if let Some(kb) = args
+                        .iter()
+                        .find_map(|arg| arg.strip_prefix("--maxkb="))
+                        .and_then(|kb| kb.parse::<u64>().ok())
+                    {
+                        rules.max_size = kb * 1024;
+                    }
+                    builtins.push(Builtin::LargeFiles(rules));
+                }
+                "detect-private-key" | "detect-aws-credentials" => {
+                    if !builtins
+                        .iter()
+                        .any(|builtin| matches!(builtin, Builtin::Secrets(_)))
+                    {
+                        builtins.push(Builtin::Secrets(SecretsRules::default()));
+                    }
+                }
+                id => {
+                    migration.note(format!(
+                        "{} from pre-commit-hooks has no rusky equivalent and was not imported",
+                        id
+                    ));
+                    continue;
+                }
+            }
+
+            if hook.stages.as_ref().is_some_and(|stages| {
+                stages
+                    .iter()
+                    .any(|stage| stage_hook(stage) != Some("pre-commit"))
+            }) {
+                migration.note(format!("{}: imported into pre-commit only", hook.id));
+            }
+        }
+
+        let any_hygiene = hygiene.conflict_markers
+            || hygiene.trailing_whitespace
+            || hygiene.final_newline
+            || hygiene.mixed_line_endings
+            || hygiene.case_conflicts;
+        if any_hygiene {
+            builtins.insert(0, Builtin::Hygiene(hygiene));
+        }
+        if !formats.is_empty() {
+            builtins.insert(
+                any_hygiene as usize,
+                Builtin::Syntax(SyntaxRules {
+                    formats,
+                    ..SyntaxRules::default()
+                }),
+            );
+        }
+
+        for builtin in builtins {
+            let mut step = HookCommand::new(builtin.name().to_string(), String::new());
+            step.builtin = Some(builtin);
+            migration.add_step("pre-commit", step);
+        }
+    }
+
+    /// pre-commit이 설치한 hook인지 확인
+    pub fn is_generated_hook(content: &str) -> bool {
+        content.contains("File generated by pre-commit")
+    }
+}
+
+/// pre-commit stage 이름을 git hook 이름으로 (`commit`, `push`는 예전 이름)
+fn stage_hook(stage: &str) -> Option<&str> {
+    let hook_name = match stage {
+        "commit" => "pre-commit",
+        "push" => "pre-push",
+        "merge-commit" => "pre-merge-commit",
+        stage => stage,
+    };
+    is_valid_hook_name(hook_name).then_some(hook_name)
+}
+
+/// 훅의 `files`와 `types`를 glob으로 (변환할 수 없으면 None)
+fn file_globs(hook: &PreCommitHook) -> Option<Vec<String>> {
+    let mut type_globs: Vec<String> = Vec::new();
+    let specific_types = hook
+        .types
+        .iter()
+        .filter(|kind| !GENERIC_TYPES.contains(&kind.as_str()))
+        .take(1)
+        .chain(hook.types_or.iter());
+    for kind in specific_types {
+        let (_, globs) = TYPE_GLOBS.iter().find(|(name, _)| name == kind)?;
+        for glob in globs.iter() {
+            if !type_globs.iter().any(|existing| existing == glob) {
+                type_globs.push(glob.to_string());
+            }
+        }
+    }
+
+    match &hook.files {
+        Some(files) => regex_to_globs(files, &type_globs),
+        None if type_globs.is_empty() => Some(vec!["*".to_string()]),
+        None => Some(type_globs),
+    }
+}
+
+/// 자주 쓰는 형태의 `files` 정규식을 glob으로 변환
+///
+/// `\.py$`, `\.(js|ts)$`, `^src/`, `^(src|tests)/.*\.rs$` 같은 형태만 지원합니다.
+fn regex_to_globs(regex: &str, type_globs: &[String]) -> Option<Vec<String>> {
+    let (anchored, rest) = match regex.strip_prefix('^') {
+        Some(rest) => (true, rest),
+        None => (false, regex),
+    };
+
+    let (rest, extensions) = match rest.strip_suffix('$') {
+        Some(rest) => {
+            let index = rest.rfind("\\.")?;
+            (&rest[..index], alternatives(&rest[index + 2..])?)
+        }
+        None => (rest, Vec::new()),
+    };
+
+    let rest = rest
+        .strip_suffix(".*")
+        .or_else(|| rest.strip_suffix(".+"))
+        .unwrap_or(rest);
+    let directories = match rest {
+        "" => Vec::new(),
+        rest => alternatives(rest.strip_suffix('/')?)?,
+    };
+    if directories.is_empty() && extensions.is_empty() {
+        return None;
+    }
+
+    let names: Vec<String> = if !extensions.is_empty() {
+        extensions
+            .iter()
+            .map(|extension| format!("*.{}", extension))
+            .collect()
+    } else if !type_globs.is_empty() {
+        type_globs.to_vec()
+    } else {
+        vec!["*".to_string()]
+    };
+    if directories.is_empty() {
+        return Some(names);
+    }
+
+    // 앞에 `^`가 없으면 어느 깊이의 디렉토리든 일치
+    let prefix = if anchored { "" } else { "**/" };
+    Some(
+        directories
+            .iter()
+            .flat_map(|directory| {
+                names
+                    .iter()
+                    .map(move |name| format!("{}{}/**/{}", prefix, directory, name))
+            })
+            .collect(),
+    )
+}
+
+/// `abc`, `(abc|def)`, `(?:abc|def)`의 선택지 (다른 정규식 문법이 있으면 None)
+fn alternatives(pattern: &str) -> Option<Vec<String>> {
+    let inner = pattern
+        .strip_prefix("(?:")
+        .or_else(|| pattern.strip_prefix('('))
+        .and_then(|inner| inner.strip_suffix(')'));
+
+    match inner {
+        Some(inner) => inner.split('|').map(unescape).collect(),
+        None => unescape(pattern).map(|literal| vec![literal]),
+    }
+}
+
+/// 정규식의 문자 그대로인 부분 (`\.` 같은 escape만 허용)
+fn unescape(literal: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut chars = literal.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let escaped = chars.next()?;
+                // `\d`, `\w` 같은 문자 class
+                if escaped.is_ascii_alphanumeric() {
+                    return None;
+                }
+                unescaped.push(escaped);
+            }
+            c if c.is_ascii_alphanumeric() || "_-/".contains(c) => unescaped.push(c),
+            _ => return None,
+        }
+    }
+
+    (!unescaped.is_empty()).then_some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(yaml: &str) -> Migration {
+        PreCommit::convert(&serde_yaml::from_str(yaml).unwrap())
+    }
+
+    fn globs(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_regex_to_globs() {
+        assert_eq!(regex_to_globs(r"\.py$", &[]), Some(globs(&["*.py"])));
+        assert_eq!(
+            regex_to_globs(r"\.(js|ts)$", &[]),
+            Some(globs(&["*.js", "*.ts"]))
+        );
+        assert_eq!(regex_to_globs(r".*\.rs$", &[]), Some(globs(&["*.rs"])));
+        assert_eq!(
+            regex_to_globs(r"^(src|tests)/.*\.rs$", &[]),
+            Some(globs(&["src/**/*.rs", "tests/**/*.rs"]))
+        );
+        assert_eq!(regex_to_globs("^docs/", &[]), Some(globs(&["docs/**/*"])));
+        assert_eq!(
+            regex_to_globs("^src/", &globs(&["*.py"])),
+            Some(globs(&["src/**/*.py"]))
+        );
+        assert_eq!(
+            regex_to_globs(r"migrations/.*\.sql$", &[]),
+            Some(globs(&["**/migrations/**/*.sql"]))
+        );
+
+        assert_eq!(regex_to_globs(r"\.jsx?$", &[]), None);
+        assert_eq!(regex_to_globs(r"^src/\w+\.py$", &[]), None);
+        assert_eq!(regex_to_globs(".*", &[]), None);
+    }
+
+    #[test]
+    fn test_convert_local_hooks() {
+        let migration = convert(
+            r#"
+repos:
+  - repo: local
+    hooks:
+      - id: eslint
+        name: eslint
+        entry: npx eslint
+        language: system
+        files: \.(js|ts)$
+        args: [--fix, --max-warnings, 0]
+      - id: cargo-test
+        entry: cargo test
+        language: system
+        pass_filenames: false
+        stages: [push]
+      - id: mypy
+        entry: mypy
+        language: python
+        types: [file, python]
+      - id: commitlint
+        entry: npx commitlint --edit
+        language: system
+        stages: [commit-msg]
+"#,
+        );
+
+        let pre_commit = &migration.hooks[0].1;
+        assert_eq!(migration.hooks[0].0, "pre-commit");
+        assert_eq!(pre_commit.commands[0].name, "eslint");
+        assert_eq!(
+            pre_commit.commands[0].run,
+            "npx eslint --fix --max-warnings 0"
+        );
+        assert_eq!(pre_commit.commands[0].glob, globs(&["*.js", "*.ts"]));
+        assert_eq!(pre_commit.commands[1].glob, globs(&["*.py", "*.pyi"]));
+
+        assert_eq!(migration.runs("pre-push"), vec!["cargo test"]);
+        assert!(migration
+            .hooks
+            .iter()
+            .find(|(name, _)| name == "pre-push")
+            .unwrap()
+            .1
+            .commands[0]
+            .glob
+            .is_empty());
+        assert_eq!(
+            migration.runs("commit-msg"),
+            vec!["npx commitlint --edit \"$1\""]
+        );
+
+        assert!(migration
+            .notes
+            .iter()
+            .any(|note| note.contains("mypy uses language 'python'")));
+    }
+
+    #[test]
+    fn test_convert_pre_commit_hooks_to_builtins() {
+        let migration = convert(
+            r#"
+repos:
+  - repo: https://github.com/pre-commit/pre-commit-hooks
+    rev: v4.5.0
+    hooks:
+      - id: trailing-whitespace
+      - id: end-of-file-fixer
+      - id: check-merge-conflict
+      - id: check-yaml
+      - id: check-json
+      - id: check-added-large-files
+        args: ['--maxkb=1000']
+      - id: detect-private-key
+      - id: name-tests-test
+  - repo: https://github.com/psf/black
+    rev: 23.1.0
+    hooks:
+      - id: black
+  - repo: meta
+    hooks:
+      - id: check-useless-excludes
+"#,
+        );
+
+        let steps = &migration.hooks[0].1.commands;
+        let names: Vec<&str> = steps.iter().map(|step| step.name.as_str()).collect();
+        assert_eq!(names, vec!["hygiene", "syntax", "large-files", "secrets"]);
+
+        match steps[0].builtin.as_ref().unwrap() {
+            Builtin::Hygiene(rules) => {
+                assert!(rules.trailing_whitespace && rules.final_newline && rules.conflict_markers);
+                assert!(!rules.mixed_line_endings && !rules.case_conflicts);
+                assert!(rules.fix);
+            }
+            other => panic!("unexpected builtin {:?}", other),
+        }
+        match steps[1].builtin.as_ref().unwrap() {
+            Builtin::Syntax(rules) => assert_eq!(rules.formats, vec![Format::Yaml, Format::Json]),
+            other => panic!("unexpected builtin {:?}", other),
+        }
+        match steps[2].builtin.as_ref().unwrap() {
+            Builtin::LargeFiles(rules) => assert_eq!(rules.max_size, 1000 * 1024),
+            other => panic!("unexpected builtin {:?}", other),
+        }
+
+        let notes = migration.notes.join("\n");
+        assert!(notes.contains("name-tests-test from pre-commit-hooks has no rusky equivalent"));
+        assert!(notes.contains("black from https://github.com/psf/black was not imported"));
+        assert!(notes.contains("meta hooks"));
+    }
+
+    #[test]
+    fn test_default_stages_and_unsupported_patterns() {
+        let migration = convert(
+            r#"
+default_install_hook_types: [pre-commit, pre-push]
+repos:
+  - repo: local
+    hooks:
+      - id: lint
+        entry: make lint
+        language: system
+        files: ^src/\w+\.c$
+        exclude: ^vendor/
+      - id: tsc
+        entry: tsc
+        language: system
+        types: [ts, tsx]
+        stages: [pre-commit]
+"#,
+        );
+
+        assert_eq!(migration.runs("pre-commit"), vec!["make lint", "tsc"]);
+        assert_eq!(migration.runs("pre-push"), vec!["make lint"]);
+        assert_eq!(migration.hooks[0].1.commands[0].glob, globs(&["*"]));
+
+        let notes = migration.notes.join("\n");
+        assert!(notes.contains("could not be converted to a glob"));
+        assert!(notes.contains("'exclude' and 'exclude_types' are not supported"));
+        assert!(notes.contains("changed files are not passed to pre-push steps"));
+        assert!(notes.contains("tsc: only 'ts' of 'types' [ts, tsx] was imported"));
+    }
+}
@@ -0,0 +1,181 @@
+use anyhow::{anyhow, Result};
+use serde_json::{Map, Value};
+use std::path::Path;
+use tokio::fs;
+
+use super::{check_package_json, read_package_json, Migration};
+use crate::cli::is_valid_hook_name;
+
+/// simple-git-hooks 설정을 담을 수 있는 JSON 파일
+const CONFIG_FILES: &[&str] = &[".simple-git-hooks.json", "simple-git-hooks.json"];
+
+/// rusky가 읽을 수 없는 simple-git-hooks 설정 파일
+const SCRIPT_FILES: &[&str] = &[
+    ".simple-git-hooks.js",
+    ".simple-git-hooks.cjs",
+    "simple-git-hooks.js",
+    "simple-git-hooks.cjs",
+];
+
+/// hook이 아닌 simple-git-hooks 설정 키
+const PRESERVE_UNUSED: &str = "preserveUnused";
+
+/// simple-git-hooks 설정 가져오기 (`package.json`의 `"simple-git-hooks"`)
+pub struct SimpleGitHooks;
+
+impl SimpleGitHooks {
+    /// 현재 디렉토리 (저장소 루트)의 simple-git-hooks 설정 읽기
+    pub async fn read() -> Result<Migration> {
+        let mut migration = Migration::default();
+        let mut found = false;
+
+        let package = read_package_json().await?;
+        if let Some(hooks) = package
+            .as_ref()
+            .and_then(|package| package.get("simple-git-hooks"))
+            .and_then(Value::as_object)
+        {
+            found = true;
+            Self::read_hooks(hooks, "package.json", &mut migration);
+        }
+        for file in CONFIG_FILES {
+            if !Path::new(file).is_file() {
+                continue;
+            }
+            let content = fs::read_to_string(file).await?;
+            let config: Value = serde_json::from_str(&content)
+                .map_err(|e| anyhow!("Failed to parse {}: {}", file, e))?;
+            if let Some(hooks) = config.as_object() {
+                found = true;
+                Self::read_hooks(hooks, file, &mut migration);
+            }
+        }
+        for file in SCRIPT_FILES {
+            if Path::new(file).is_file() {
+                found = true;
+                migration.note(format!(
+                    "{} is JavaScript and was not imported; add its hooks with 'rusky add'",
+                    file
+                ));
+            }
+        }
+
+        if !found {
+            return Err(anyhow!(
+                "No simple-git-hooks configuration found (\"simple-git-hooks\" in package.json)"
+            ));
+        }
+
+        if let Some(package) = &package {
+            check_package_json(package, "simple-git-hooks", &mut migration);
+        }
+
+        Ok(migration)
+    }
+
+    /// `{ "pre-commit": "npx lint-staged" }` 읽기
+    fn read_hooks(hooks: &Map<String, Value>, source: &str, migration: &mut Migration) {
+        for (hook_name, command) in hooks {
+            if hook_name == PRESERVE_UNUSED {
+                continue;
+            }
+            if !is_valid_hook_name(hook_name) {
+                migration.note(format!(
+                    "{} in {} is not a git hook and was not imported",
+                    hook_name, source
+                ));
+                continue;
+            }
+            match command.as_str().map(str::trim) {
+                Some(command) if !command.is_empty() => {
+                    migration.add(hook_name, vec![command.to_string()])
+                }
+                _ => migration.note(format!(
+                    "{} in {} is not a command and was not imported",
+                    hook_name, source
+                )),
+            }
+        }
+    }
+
+    /// simple-git-hooks가 설치한 hook인지 확인
+    pub fn is_generated_hook(content: &str) -> bool {
+        content.contains("SKIP_SIMPLE_GIT_HOOKS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_read_hooks() {
+        let hooks: Value = serde_json::from_str(
+            r#"{
+                "pre-commit": "npx lint-staged",
+                "commit-msg": "npx commitlint --edit \"$1\"",
+                "preserveUnused": ["pre-push"],
+                "lint": "npm run lint"
+            }"#,
+        )
+        .unwrap();
+
+        let mut migration = Migration::default();
+        SimpleGitHooks::read_hooks(hooks.as_object().unwrap(), "package.json", &mut migration);
+
+        assert_eq!(migration.runs("pre-commit"), vec!["npx lint-staged"]);
+        assert_eq!(
+            migration.runs("commit-msg"),
+            vec!["npx commitlint --edit \"$1\""]
+        );
+        assert_eq!(
+            migration.notes,
+            vec!["lint in package.json is not a git hook and was not imported"]
+        );
+    }
+
+    #[test]
+    fn test_is_generated_hook() {
+        let hook = "#!/bin/sh\n\nif [ \"$SKIP_SIMPLE_GIT_HOOKS\" = \"1\" ]; then\n    exit 0\nfi\n\nnpx lint-staged\n";
+        assert!(SimpleGitHooks::is_generated_hook(hook));
+        assert!(!SimpleGitHooks::is_generated_hook(
+            "#!/bin/sh\nnpx lint-staged\n"
+        ));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_read_package_json_and_config_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        fs::write(
+            "package.json",
+            r#"{
+                "scripts": { "prepare": "simple-git-hooks" },
+                "devDependencies": { "simple-git-hooks": "^2.9.0" },
+                "simple-git-hooks": { "pre-commit": "npm run lint" }
+            }"#,
+        )
+        .await
+        .unwrap();
+        fs::write(".simple-git-hooks.json", r#"{ "pre-push": "npm test" }"#)
+            .await
+            .unwrap();
+
+        let migration = SimpleGitHooks::read().await.unwrap();
+        assert_eq!(migration.runs("pre-commit"), vec!["npm run lint"]);
+        assert_eq!(migration.runs("pre-push"), vec!["npm test"]);
+        assert_eq!(migration.notes.len(), 2);
+
+        fs::remove_file("package.json").await.unwrap();
+        fs::remove_file(".simple-git-hooks.json").await.unwrap();
+        assert!(SimpleGitHooks::read().await.is_err());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -2,10 +2,20 @@ use anyhow::Result;
 use clap::{Parser, Subcommand};
 use colored::*;
 
+mod cache;
+mod checks;
 mod cli;
+mod conditions;
 mod config;
+mod context;
+mod doctor;
 mod git;
 mod hooks;
+mod import;
+mod runner;
+mod skip;
+mod staged;
+mod stash;
 
 #[derive(Parser)]
 #[command(name = "rusky")]
@@ -26,18 +36,69 @@ enum Commands {
         hook: String,
         /// Command to run
         command: String,
+        /// Step name (an existing step with the same name is replaced)
+        #[arg(long)]
+        name: Option<String>,
+        /// Run only against staged files matching this glob (repeatable)
+        #[arg(long = "glob")]
+        globs: Vec<String>,
     },
     /// Remove a git hook
     Remove {
         /// Hook name to remove
         hook: String,
+        /// Remove only this step instead of the whole hook
+        step: Option<String>,
     },
     /// List all configured hooks
     List,
     /// Install git hooks
-    Install,
+    Install {
+        /// Install hooks into this directory and point core.hooksPath at it
+        #[arg(long, num_args = 0..=1, default_missing_value = ".rusky/_")]
+        hooks_path: Option<String>,
+        /// Take over core.hooksPath even if another tool has set it
+        #[arg(long)]
+        force: bool,
+    },
     /// Uninstall git hooks
     Uninstall,
+    /// Run the commands configured for a hook (called by generated hook files)
+    Run {
+        /// Hook name to run
+        hook: String,
+        /// Show why each step runs or is skipped
+        #[arg(long)]
+        explain: bool,
+        /// Arguments passed by git to the hook
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
+    /// Restore unstaged changes hidden by an interrupted hook run
+    Restore,
+    /// Import hooks from another hook manager
+    Import {
+        /// Tool to import from
+        tool: import::Tool,
+    },
+    /// Check installed hooks against the config
+    #[command(alias = "status")]
+    Doctor {
+        /// Repair the problems that were found
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Manage cached step results
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommands {
+    /// Remove all cached step results
+    Clear,
 }
 
 #[tokio::main]
@@ -49,26 +110,61 @@ async fn main() -> Result<()> {
             println!("{}", "🚀 Initializing rusky...".green());
             cli::init().await?;
         }
-        Commands::Add { hook, command } => {
+        Commands::Add {
+            hook,
+            command,
+            name,
+            globs,
+        } => {
             println!("{}", format!("📝 Adding {} hook...", hook).green());
-            cli::add_hook(&hook, &command).await?;
+            cli::add_hook(&hook, &command, name.as_deref(), &globs).await?;
         }
-        Commands::Remove { hook } => {
+        Commands::Remove { hook, step } => {
             println!("{}", format!("🗑️  Removing {} hook...", hook).yellow());
-            cli::remove_hook(&hook).await?;
+            cli::remove_hook(&hook, step.as_deref()).await?;
         }
         Commands::List => {
             println!("{}", "📋 Listing hooks...".blue());
             cli::list_hooks().await?;
         }
-        Commands::Install => {
+        Commands::Install { hooks_path, force } => {
             println!("{}", "⚙️  Installing git hooks...".green());
-            cli::install_hooks().await?;
+            cli::install_hooks(hooks_path.as_deref(), force).await?;
         }
         Commands::Uninstall => {
             println!("{}", "🧹 Uninstalling git hooks...".yellow());
             cli::uninstall_hooks().await?;
         }
+        Commands::Run {
+            hook,
+            explain,
+            args,
+        } => {
+            let code = cli::run_hook(&hook, &args, explain).await?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+        Commands::Restore => {
+            println!("{}", "♻️  Restoring hidden changes...".green());
+            cli::restore().await?;
+        }
+        Commands::Import { tool } => {
+            println!("{}", "📥 Importing hooks...".green());
+            cli::import(tool).await?;
+        }
+        Commands::Doctor { fix } => {
+            println!("{}", "🩺 Checking git hooks...".blue());
+            if !cli::doctor(fix).await? {
+                std::process::exit(1);
+            }
+        }
+        Commands::Cache {
+            command: CacheCommands::Clear,
+        } => {
+            println!("{}", "🗑️  Clearing cached results...".yellow());
+            cli::clear_cache().await?;
+        }
     }
 
     Ok(())
@@ -0,0 +1,1507 @@
+use anyhow::Result;
+use colored::*;
+use serde_json::{Map, Value};
+use std::os::unix::fs::PermissionsExt;
+use std::os::unix::process::ExitStatusExt;
+use std::process::{ExitStatus, Output, Stdio};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::process::{Child, Command};
+use tokio::sync::{watch, Semaphore};
+use tokio::task::JoinSet;
+
+use crate::cache::{CacheStats, ResultCache};
+use crate::checks::{Builtin, Report};
+use crate::conditions::{Check, Facts};
+use crate::config::{ChainMode, Config, HookCommand, HookConfig};
+use crate::context::HookContext;
+use crate::git::Git;
+use crate::hooks::HookManager;
+use crate::skip::Skip;
+use crate::staged::{chunk_files, FileMatcher, MAX_ARGS_LENGTH};
+use crate::stash::UnstagedStash;
+
+/// 원래 hook을 실행할 때 표시하는 단계 이름
+const ORIGINAL_HOOK_STEP: &str = "original hook";
+
+/// 제한 시간을 넘겨 종료된 단계의 종료 코드 (GNU `timeout`과 같은 값)
+const TIMEOUT_EXIT_CODE: i32 = 124;
+
+/// Ctrl-C로 중단된 단계의 종료 코드
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// 제한 시간이 지나 SIGTERM을 보낸 뒤 SIGKILL을 보내기까지 기다리는 시간
+const KILL_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// `rusky run`의 실행 옵션
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// 단계마다 실행하거나 건너뛴 이유 표시
+    pub explain: bool,
+}
+
+pub struct HookRunner;
+
+impl HookRunner {
+    /// 설정 파일에서 hook 명령어를 읽어 실행하고 종료 코드를 반환
+    pub async fn run(context: &HookContext, options: RunOptions) -> Result<i32> {
+        let hook_name = context.hook_name.as_str();
+
+        let skip = Skip::from_env();
+        if skip.disabled {
+            eprintln!(
+                "{}",
+                format!("○ rusky > {} skipped (RUSKY=0)", hook_name).dimmed()
+            );
+            return Ok(0);
+        }
+
+        // hook은 항상 저장소 루트 기준으로 실행
+        if let Ok(git_root) = Git::get_git_root().await {
+            std::env::set_current_dir(git_root)?;
+        }
+
+        let config = Config::load().await?;
+        let hook = match config.get_hook(hook_name) {
+            Some(hook) => hook,
+            None => return Ok(0),
+        };
+
+        if let Some(ci) = skip.ci.as_deref().filter(|_| hook.skip_in_ci) {
+            eprintln!(
+                "{}",
+                format!("○ rusky > {} skipped in CI ({})", hook_name, ci).dimmed()
+            );
+            return Ok(0);
+        }
+
+        eprintln!("{}", format!("🐺 rusky > {}", hook_name).bold());
+
+        if hook.stash && UnstagedStash::is_pending().await? {
+            eprintln!(
+                "{}",
+                "❌ rusky > a previous run was interrupted while unstaged changes were hidden. Run 'rusky restore' to get them back."
+                    .red()
+            );
+            return Ok(1);
+        }
+
+        // 단계가 unstaged 변경을 보거나 수정하지 않도록 숨겨 두기
+        let stash = if hook.stash {
+            Some(UnstagedStash::save(hook.stash_untracked).await?)
+        } else {
+            None
+        };
+
+        let mut cache = CacheStats::default();
+        let result = Self::run_steps(context, hook, &skip, options, &mut cache).await;
+
+        if let Some(stash) = stash {
+            let succeeded = matches!(result, Ok(None));
+            let restaged = if succeeded {
+                stash.restage().await.map(drop)
+            } else {
+                Ok(())
+            };
+            // 다시 staged 하지 못했어도 숨겨 둔 변경은 먼저 되돌린 뒤 에러 보고
+            // (단계가 고친 내용은 unstaged 변경으로 남음)
+            let restored = stash.restore(succeeded).await;
+            restaged.and(restored)?;
+        }
+
+        let failure = result?;
+
+        let summary = cache
+            .summary()
+            .map(|summary| format!(" ({})", summary))
+            .unwrap_or_default();
+
+        if let Some((step_name, code)) = failure {
+            eprintln!(
+                "{}",
+                format!("❌ rusky > {} failed at {}{}", hook_name, step_name, summary).red()
+            );
+            return Ok(code);
+        }
+
+        eprintln!(
+            "{}",
+            format!("✅ rusky > {} completed{}", hook_name, summary).green()
+        );
+        Ok(0)
+    }
+
+    /// 단계를 실행하고 실패한 단계의 이름과 종료 코드 반환
+    async fn run_steps(
+        context: &HookContext,
+        hook: &HookConfig,
+        skip: &Skip,
+        options: RunOptions,
+        cache: &mut CacheStats,
+    ) -> Result<Option<(String, i32)>> {
+        // 제한 시간이 있는 단계가 있으면 Ctrl-C 감시를 한 번만 설치해서 함께 사용
+        let timed =
+            hook.timeout.is_some() || hook.commands.iter().any(|step| step.timeout.is_some());
+        let interrupt = if timed {
+            Interrupt::listen()
+        } else {
+            Interrupt::none()
+        };
+
+        if let Some(ChainMode::Unknown(value)) = &hook.chain {
+            warn_unknown_value(&context.hook_name, "chain", value);
+        }
+        if hook.chain == Some(ChainMode::Before) {
+            let failure = Self::run_original_hook(context, hook.timeout, &interrupt).await?;
+            if failure.is_some() {
+                return Ok(failure);
+            }
+        }
+
+        let steps = Self::plan(context, hook, skip, options, cache).await?;
+        let failure = if hook.parallel {
+            Self::run_parallel(context, hook, steps, &interrupt).await?
+        } else {
+            Self::run_sequential(context, steps, &interrupt).await?
+        };
+
+        if failure.is_none() && hook.chain == Some(ChainMode::After) {
+            return Self::run_original_hook(context, hook.timeout, &interrupt).await;
+        }
+
+        Ok(failure)
+    }
+
+    /// 설치 시 백업해 둔 원래 hook 실행
+    async fn run_original_hook(
+        context: &HookContext,
+        timeout: Option<u64>,
+        interrupt: &Interrupt,
+    ) -> Result<Option<(String, i32)>> {
+        let backup_path = HookManager::get_backup_path(&context.hook_name).await?;
+        let Ok(metadata) = tokio::fs::metadata(&backup_path).await else {
+            return Ok(None);
+        };
+        if !metadata.is_file() {
+            return Ok(None);
+        }
+        // git과 마찬가지로 실행 권한이 없는 hook은 건너뜀
+        if metadata.permissions().mode() & 0o111 == 0 {
+            eprintln!(
+                "{}",
+                format!(
+                    "⚠️  The original {} hook ({}) was ignored because it's not set as executable",
+                    context.hook_name,
+                    backup_path.display()
+                )
+                .yellow()
+            );
+            return Ok(None);
+        }
+
+        eprintln!("{} {}", "▶".blue(), ORIGINAL_HOOK_STEP.bold());
+        let mut command = Command::new(&backup_path);
+        command.args(&context.args);
+        let limit = timeout.map(Duration::from_secs);
+        let mut child = Self::spawn(command, context, limit).await?;
+        let Some(status) = Self::wait(&mut child, limit, interrupt).await? else {
+            eprintln!("{}", timeout_message(ORIGINAL_HOOK_STEP, timeout));
+            return Ok(Some((ORIGINAL_HOOK_STEP.to_string(), TIMEOUT_EXIT_CODE)));
+        };
+        if !status.success() {
+            return Ok(Some((
+                ORIGINAL_HOOK_STEP.to_string(),
+                status.code().unwrap_or(1),
+            )));
+        }
+
+        Ok(None)
+    }
+
+    /// 실행할 단계와 각 단계의 실행 인자 결정
+    ///
+    /// `RUSKY_SKIP`에 있거나 `when`/`skip` 조건에 맞지 않는 단계는 건너뜁니다.
+    /// `glob`이 있는 단계는 일치하는 staged 파일을 인자로 받아 실행되고,
+    /// 일치하는 파일이 없으면 건너뜁니다.
+    /// `cache`를 켠 단계는 같은 입력으로 성공한 적이 있으면 건너뜁니다.
+    async fn plan(
+        context: &HookContext,
+        hook: &HookConfig,
+        skip: &Skip,
+        options: RunOptions,
+        cache: &mut CacheStats,
+    ) -> Result<Vec<PlannedStep>> {
+        let mut facts = Facts::new(context);
+        let mut staged_files: Option<Vec<String>> = None;
+        let mut steps = Vec::new();
+
+        for step in &hook.commands {
+            if skip.skips_step(&step.name) {
+                print_skipped(&step.name, "skipped by RUSKY_SKIP");
+                continue;
+            }
+
+            // `--explain`에 표시할, 단계를 실행하기로 한 이유
+            let mut reasons = Vec::new();
+
+            if let Some(when) = &step.when {
+                warn_unknown_keys(&step.name, "when", &when.extra);
+                let checks = when.evaluate(&mut facts).await?;
+                if checks.iter().any(|check| !check.holds) {
+                    let shown = explained(&checks, options, |check| !check.holds);
+                    print_skipped(&step.name, &format!("when: {}", shown));
+                    continue;
+                }
+                reasons.extend(checks.iter().map(|check| format!("when: {}", check.description)));
+            }
+
+            if let Some(condition) = &step.skip {
+                warn_unknown_keys(&step.name, "skip", &condition.extra);
+                let checks = condition.evaluate(&mut facts).await?;
+                if checks.iter().any(|check| check.holds) {
+                    let shown = explained(&checks, options, |check| check.holds);
+                    print_skipped(&step.name, &format!("skip: {}", shown));
+                    continue;
+                }
+                reasons.extend(checks.iter().map(|check| format!("skip: {}", check.description)));
+            }
+
+            let files = if step.glob.is_empty() {
+                None
+            } else {
+                if staged_files.is_none() {
+                    staged_files = Some(Git::get_staged_files().await?);
+                }
+                let matcher = FileMatcher::new(&step.glob)?;
+                let files = matcher.filter(staged_files.as_deref().unwrap_or_default());
+
+                if files.is_empty() {
+                    print_skipped(&step.name, "no matching staged files");
+                    continue;
+                }
+                reasons.push(format!(
+                    "{} staged file(s) match {}",
+                    files.len(),
+                    step.glob.join(", ")
+                ));
+                Some(files)
+            };
+
+            let cache_key = match &step.cache {
+                Some(config) => {
+                    warn_unknown_keys(&step.name, "cache", &config.extra);
+                    // 충돌 중인 index처럼 키를 만들 수 없으면 캐시 없이 실행
+                    let key = ResultCache::key(context, step, config, files.as_deref(), hook.stash)
+                        .await
+                        .ok();
+                    if key.as_deref().is_some_and(ResultCache::contains) {
+                        cache.hits += 1;
+                        eprintln!(
+                            "{} {} {}",
+                            "✔".green(),
+                            step.name.dimmed(),
+                            "(cached)".dimmed()
+                        );
+                        continue;
+                    }
+                    cache.misses += 1;
+                    reasons.push("no cached result".to_string());
+                    key
+                }
+                None => None,
+            };
+
+            if options.explain {
+                let reasons = if reasons.is_empty() {
+                    "no conditions".to_string()
+                } else {
+                    reasons.join("; ")
+                };
+                eprintln!(
+                    "{} {} {}",
+                    "→".blue(),
+                    step.name.bold(),
+                    format!("(runs: {})", reasons).dimmed()
+                );
+            }
+
+            steps.push(PlannedStep {
+                step: step.clone(),
+                files,
+                timeout: step.timeout.or(hook.timeout),
+                cache_key,
+            });
+        }
+
+        Ok(steps)
+    }
+
+    /// 단계를 순서대로 실행하고 처음 실패한 단계에서 중단
+    async fn run_sequential(
+        context: &HookContext,
+        steps: Vec<PlannedStep>,
+        interrupt: &Interrupt,
+    ) -> Result<Option<(String, i32)>> {
+        for planned in &steps {
+            eprintln!("{} {}", "▶".blue(), planned.step.name.bold());
+
+            let status = planned.execute(context, interrupt).await?;
+            if !status.success() {
+                return Ok(Some((planned.step.name.clone(), status.code().unwrap_or(1))));
+            }
+            planned.remember_success().await?;
+        }
+
+        Ok(None)
+    }
+
+    /// 단계를 동시에 실행하고, 끝난 순서대로 단계별 출력을 묶어서 표시
+    async fn run_parallel(
+        context: &HookContext,
+        hook: &HookConfig,
+        steps: Vec<PlannedStep>,
+        interrupt: &Interrupt,
+    ) -> Result<Option<(String, i32)>> {
+        let max_jobs = hook.max_jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|jobs| jobs.get())
+                .unwrap_or(4)
+        });
+        let semaphore = Arc::new(Semaphore::new(max_jobs.max(1)));
+
+        let mut tasks = JoinSet::new();
+        for (index, planned) in steps.into_iter().enumerate() {
+            let semaphore = semaphore.clone();
+            let context = context.clone();
+            let interrupt = interrupt.clone();
+
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await?;
+                let output = planned.execute_captured(&context, &interrupt).await?;
+                anyhow::Ok((index, planned, output))
+            });
+        }
+
+        let mut failures = Vec::new();
+        let mut error = None;
+        while let Some(result) = tasks.join_next().await {
+            // 한 단계에서 오류가 나도 나머지 단계가 끝날 때까지 기다림
+            // (JoinSet을 버리면 별도 process group의 명령어가 hook이 끝난 뒤에도 남음)
+            let finished = result.map_err(anyhow::Error::from).and_then(|result| result);
+            let (index, planned, output) = match finished {
+                Ok(finished) => finished,
+                Err(e) => {
+                    error.get_or_insert(e);
+                    continue;
+                }
+            };
+            Self::print_step_output(&planned.step, &output);
+
+            if output.status.success() {
+                if let Err(e) = planned.remember_success().await {
+                    error.get_or_insert(e);
+                }
+            } else {
+                failures.push((index, planned.step.name, output.status.code().unwrap_or(1)));
+            }
+        }
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        // 여러 단계가 실패하면 설정상 가장 앞선 단계를 보고
+        failures.sort_by_key(|(index, _, _)| *index);
+        Ok(failures
+            .into_iter()
+            .next()
+            .map(|(_, name, code)| (name, code)))
+    }
+
+    /// 병렬 실행된 단계의 출력을 한 번에 표시
+    fn print_step_output(step: &HookCommand, output: &Output) {
+        let marker = if output.status.success() {
+            "✔".green()
+        } else {
+            "✖".red()
+        };
+        eprintln!("{} {}", marker, step.name.bold());
+        eprint!("{}", String::from_utf8_lossy(&output.stdout));
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    }
+
+    /// `sh -c`로 명령어 실행 (인자는 $1, $2 ... 로 전달)
+    ///
+    /// 제한 시간을 넘겨 종료되었으면 None을 반환합니다.
+    async fn execute(
+        context: &HookContext,
+        command: &str,
+        args: &[String],
+        timeout: Option<Duration>,
+        interrupt: &Interrupt,
+    ) -> Result<Option<ExitStatus>> {
+        let shell = Self::shell(&context.hook_name, command, args);
+        let mut child = Self::spawn(shell, context, timeout).await?;
+        Self::wait(&mut child, timeout, interrupt).await
+    }
+
+    /// 출력을 버퍼에 모으면서 명령어 실행 (제한 시간을 넘겼는지 함께 반환)
+    async fn execute_captured(
+        context: &HookContext,
+        command: &str,
+        args: &[String],
+        timeout: Option<Duration>,
+        interrupt: &Interrupt,
+    ) -> Result<(Output, bool)> {
+        let mut shell = Self::shell(&context.hook_name, command, args);
+        shell.stdout(Stdio::piped()).stderr(Stdio::piped());
+        if context.stdin.is_none() {
+            shell.stdin(Stdio::null());
+        }
+        let mut child = Self::spawn(shell, context, timeout).await?;
+
+        // 종료를 기다리는 동안 파이프가 가득 차지 않도록 출력을 따로 읽기
+        let stdout = read_pipe(child.stdout.take());
+        let stderr = read_pipe(child.stderr.take());
+        let status = Self::wait(&mut child, timeout, interrupt).await?;
+
+        let output = Output {
+            status: status.unwrap_or_else(|| exit_status(TIMEOUT_EXIT_CODE)),
+            stdout: stdout.await??,
+            stderr: stderr.await??,
+        };
+        Ok((output, status.is_none()))
+    }
+
+    /// 명령어를 시작하고 git이 넘겨준 표준 입력이 있으면 전달
+    ///
+    /// 표준 입력은 한 번만 읽을 수 있으므로 각 단계에 같은 내용을 다시 씁니다.
+    /// 제한 시간이 있으면 자식 프로세스까지 함께 종료할 수 있도록 별도 process group에서 실행합니다.
+    async fn spawn(
+        mut command: Command,
+        context: &HookContext,
+        timeout: Option<Duration>,
+    ) -> Result<Child> {
+        if context.stdin.is_some() {
+            command.stdin(Stdio::piped());
+        }
+        if timeout.is_some() {
+            command.process_group(0);
+        }
+        // 기다리던 작업이 취소되어도 명령어가 남지 않도록 함
+        command.kill_on_drop(true);
+        let mut child = command.spawn()?;
+
+        if let (Some(mut stdin), Some(input)) = (child.stdin.take(), context.stdin.clone()) {
+            // 입력을 읽지 않고 끝나는 명령어도 있으므로 쓰기 오류는 무시
+            tokio::spawn(async move {
+                let _ = stdin.write_all(&input).await;
+            });
+        }
+
+        Ok(child)
+    }
+
+    /// 명령어가 끝나길 기다리고, 제한 시간을 넘기면 process group을 종료한 뒤 None 반환
+    ///
+    /// 별도 process group의 명령어에는 터미널의 Ctrl-C가 전달되지 않으므로 직접 종료합니다.
+    async fn wait(
+        child: &mut Child,
+        timeout: Option<Duration>,
+        interrupt: &Interrupt,
+    ) -> Result<Option<ExitStatus>> {
+        let Some(timeout) = timeout else {
+            return Ok(Some(child.wait().await?));
+        };
+
+        tokio::select! {
+            status = child.wait() => Ok(Some(status?)),
+            _ = tokio::time::sleep(timeout) => {
+                Self::terminate(child).await?;
+                Ok(None)
+            }
+            _ = interrupt.pressed() => {
+                Self::terminate(child).await?;
+                Ok(Some(exit_status(INTERRUPTED_EXIT_CODE)))
+            }
+        }
+    }
+
+    /// process group에 SIGTERM을 보내고, 유예 시간이 지나면 남은 프로세스에 SIGKILL 보내기
+    async fn terminate(child: &mut Child) -> Result<()> {
+        let Some(pid) = child.id() else {
+            return Ok(());
+        };
+        let group = -(pid as libc::pid_t);
+
+        // SAFETY: spawn에서 만든 자식의 process group에만 signal을 보냄
+        unsafe { libc::kill(group, libc::SIGTERM) };
+        let _ = tokio::time::timeout(KILL_GRACE_PERIOD, child.wait()).await;
+
+        // SIGTERM을 무시했거나 먼저 끝난 셸이 남긴 프로세스 정리
+        unsafe { libc::kill(group, libc::SIGKILL) };
+        child.wait().await?;
+        Ok(())
+    }
+
+    fn shell(hook_name: &str, command: &str, args: &[String]) -> Command {
+        let mut shell = Command::new("sh");
+        shell.arg("-c").arg(command).arg(hook_name).args(args);
+        shell
+    }
+}
+
+/// 한 번의 실행에서 모든 단계가 함께 쓰는 Ctrl-C 감시
+#[derive(Clone)]
+struct Interrupt {
+    pressed: watch::Receiver<bool>,
+    /// 마지막 복사본이 사라지면 감시 작업 종료
+    _listener: Option<Arc<Listener>>,
+}
+
+struct Listener(tokio::task::AbortHandle);
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl Interrupt {
+    /// Ctrl-C 감시 시작
+    fn listen() -> Self {
+        let (sender, pressed) = watch::channel(false);
+        let task = tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                let _ = sender.send(true);
+            }
+        });
+        Self {
+            pressed,
+            _listener: Some(Arc::new(Listener(task.abort_handle()))),
+        }
+    }
+
+    /// 감시하지 않음 (Ctrl-C는 기본 동작대로 rusky와 명령어를 함께 종료)
+    fn none() -> Self {
+        Self {
+            pressed: watch::channel(false).1,
+            _listener: None,
+        }
+    }
+
+    /// Ctrl-C가 눌릴 때까지 기다리기 (이미 눌렸으면 바로 끝남)
+    async fn pressed(&self) {
+        let mut pressed = self.pressed.clone();
+        if pressed.wait_for(|pressed| *pressed).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+/// 실행이 결정된 단계
+struct PlannedStep {
+    step: HookCommand,
+    /// `glob` 단계에 넘길 staged 파일 (없으면 hook 인자를 그대로 전달)
+    files: Option<Vec<String>>,
+    /// 단계 또는 hook에 설정된 제한 시간 (초)
+    timeout: Option<u64>,
+    /// 성공하면 결과를 저장할 캐시 키 (`cache`를 켠 단계만)
+    cache_key: Option<String>,
+}
+
+impl PlannedStep {
+    /// 실행할 명령어와 각 실행에 넘길 인자 묶음
+    ///
+    /// 명령어의 `{msg_file}` 같은 이름 있는 값은 hook 인자로 바뀝니다.
+    fn invocations(&self, context: &HookContext) -> (String, Vec<Vec<String>>) {
+        let run = context.expand(&self.step.run);
+        match &self.files {
+            Some(files) => (
+                format!("{} \"$@\"", run),
+                chunk_files(files, MAX_ARGS_LENGTH),
+            ),
+            None => (run, vec![context.args.clone()]),
+        }
+    }
+
+    /// 성공한 결과를 캐시에 기록
+    async fn remember_success(&self) -> Result<()> {
+        if let Some(key) = &self.cache_key {
+            ResultCache::store(key, &self.step.name).await?;
+        }
+        Ok(())
+    }
+
+    /// 내장 검사를 제한 시간 안에서 실행 (시간을 넘기면 None)
+    async fn run_builtin(
+        &self,
+        builtin: &Builtin,
+        context: &HookContext,
+    ) -> Result<Option<Report>> {
+        let run = builtin.run(context, self.files.as_deref());
+        match self.timeout {
+            Some(timeout) => match tokio::time::timeout(Duration::from_secs(timeout), run).await {
+                Ok(report) => report.map(Some),
+                Err(_) => Ok(None),
+            },
+            None => run.await.map(Some),
+        }
+    }
+
+    async fn execute(&self, context: &HookContext, interrupt: &Interrupt) -> Result<ExitStatus> {
+        if let Some(builtin) = &self.step.builtin {
+            let Some(report) = self.run_builtin(builtin, context).await? else {
+                eprintln!("{}", timeout_message(&self.step.name, self.timeout));
+                return Ok(exit_status(TIMEOUT_EXIT_CODE));
+            };
+            eprint!("{}", report.render());
+            return Ok(report_status(&report));
+        }
+
+        let (command, invocations) = self.invocations(context);
+
+        let timeout = self.timeout.map(Duration::from_secs);
+
+        let mut status = ExitStatus::default();
+        for invocation in &invocations {
+            let Some(finished) =
+                HookRunner::execute(context, &command, invocation, timeout, interrupt).await?
+            else {
+                eprintln!("{}", timeout_message(&self.step.name, self.timeout));
+                return Ok(exit_status(TIMEOUT_EXIT_CODE));
+            };
+            status = finished;
+            if !status.success() {
+                break;
+            }
+        }
+
+        Ok(status)
+    }
+
+    async fn execute_captured(
+        &self,
+        context: &HookContext,
+        interrupt: &Interrupt,
+    ) -> Result<Output> {
+        if let Some(builtin) = &self.step.builtin {
+            let Some(report) = self.run_builtin(builtin, context).await? else {
+                let message = format!("{}\n", timeout_message(&self.step.name, self.timeout));
+                return Ok(Output {
+                    status: exit_status(TIMEOUT_EXIT_CODE),
+                    stdout: Vec::new(),
+                    stderr: message.into_bytes(),
+                });
+            };
+            return Ok(Output {
+                status: report_status(&report),
+                stdout: Vec::new(),
+                stderr: report.render().into_bytes(),
+            });
+        }
+
+        let (command, invocations) = self.invocations(context);
+
+        let mut combined = Output {
+            status: ExitStatus::default(),
+            stdout: Vec::new(),
+            stderr: Vec::new(),
+        };
+        let timeout = self.timeout.map(Duration::from_secs);
+        for invocation in &invocations {
+            let (output, timed_out) =
+                HookRunner::execute_captured(context, &command, invocation, timeout, interrupt)
+                    .await?;
+            combined.status = output.status;
+            combined.stdout.extend(output.stdout);
+            combined.stderr.extend(output.stderr);
+            if timed_out {
+                let message = format!("{}\n", timeout_message(&self.step.name, self.timeout));
+                combined.stderr.extend(message.into_bytes());
+            }
+            if !combined.status.success() {
+                break;
+            }
+        }
+
+        Ok(combined)
+    }
+}
+
+/// 내장 검사 결과를 명령어 종료 코드처럼 표현
+fn report_status(report: &Report) -> ExitStatus {
+    exit_status(if report.is_success() { 0 } else { 1 })
+}
+
+/// 건너뛴 단계와 그 이유 표시
+fn print_skipped(step_name: &str, reason: &str) {
+    eprintln!(
+        "{} {} {}",
+        "○".dimmed(),
+        step_name.dimmed(),
+        format!("({})", reason).dimmed()
+    );
+}
+
+/// 이 버전이 모르는 설정 키는 무시하고 경고 (오타이거나 새 버전에서 추가된 키)
+fn warn_unknown_keys(step_name: &str, field: &str, extra: &Map<String, Value>) {
+    if extra.is_empty() {
+        return;
+    }
+
+    let keys: Vec<&str> = extra.keys().map(String::as_str).collect();
+    eprintln!(
+        "{}",
+        format!(
+            "⚠️  {}: ignoring unknown {} key(s) {} (a typo, or added in a newer rusky?)",
+            step_name,
+            field,
+            keys.join(", ")
+        )
+        .yellow()
+    );
+}
+
+/// 이 버전이 모르는 설정 값은 무시하고 경고
+fn warn_unknown_value(name: &str, field: &str, value: &Value) {
+    eprintln!(
+        "{}",
+        format!(
+            "⚠️  {}: ignoring unknown {} value {} (a typo, or added in a newer rusky?)",
+            name, field, value
+        )
+        .yellow()
+    );
+}
+
+/// 단계를 건너뛴 이유 (`--explain`이면 확인한 조건 전체)
+fn explained(checks: &[Check], options: RunOptions, decisive: impl Fn(&Check) -> bool) -> String {
+    if options.explain {
+        return checks
+            .iter()
+            .map(|check| {
+                let marker = if decisive(check) { "✗" } else { "✓" };
+                format!("{} {}", marker, check.description)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+
+    checks
+        .iter()
+        .find(|check| decisive(check))
+        .map(|check| check.description.clone())
+        .unwrap_or_default()
+}
+
+/// 종료 코드로 끝난 프로세스의 ExitStatus
+fn exit_status(code: i32) -> ExitStatus {
+    ExitStatus::from_raw(code << 8)
+}
+
+/// 제한 시간을 넘겨 종료된 단계의 안내
+fn timeout_message(step_name: &str, timeout: Option<u64>) -> ColoredString {
+    format!(
+        "⏱️  {} timed out after {}s and was stopped",
+        step_name,
+        timeout.unwrap_or_default()
+    )
+    .red()
+}
+
+/// 파이프의 출력을 끝까지 읽는 작업 시작
+fn read_pipe(
+    pipe: Option<impl AsyncRead + Unpin + Send + 'static>,
+) -> tokio::task::JoinHandle<Result<Vec<u8>>> {
+    tokio::spawn(async move {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            pipe.read_to_end(&mut buffer).await?;
+        }
+        Ok(buffer)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conditions::Condition;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    async fn setup_test_git_repo() -> (TempDir, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+
+        env::set_current_dir(temp_dir.path()).unwrap();
+        tokio::process::Command::new("git")
+            .args(["init"])
+            .output()
+            .await
+            .unwrap();
+
+        (temp_dir, original_dir)
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_configured_hook() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_hook("pre-commit".to_string(), "touch ran.txt".to_string());
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert!(std::path::Path::new("ran.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_returns_exit_code() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_hook("pre-push".to_string(), "exit 3".to_string());
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-push", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 3);
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_steps_in_order_and_stop_on_failure() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "first".to_string(), "echo first >> order.txt".to_string());
+        config.add_step("pre-commit".to_string(), "second".to_string(), "echo second >> order.txt".to_string());
+        config.add_step("pre-commit".to_string(), "fail".to_string(), "exit 1".to_string());
+        config.add_step("pre-commit".to_string(), "never".to_string(), "echo never >> order.txt".to_string());
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 1);
+        let order = tokio::fs::read_to_string("order.txt").await.unwrap();
+        assert_eq!(order, "first\nsecond\n");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_parallel_steps() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "a".to_string(), "sleep 0.3 && touch a.txt".to_string());
+        config.add_step("pre-commit".to_string(), "b".to_string(), "sleep 0.3 && touch b.txt".to_string());
+        config.add_step("pre-commit".to_string(), "c".to_string(), "sleep 0.3 && touch c.txt".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.parallel = true;
+        hook.max_jobs = Some(3);
+        config.save().await.unwrap();
+
+        let started = std::time::Instant::now();
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert!(started.elapsed() < std::time::Duration::from_millis(850));
+        for file in ["a.txt", "b.txt", "c.txt"] {
+            assert!(std::path::Path::new(file).exists());
+        }
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_parallel_fails_if_any_step_fails() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "ok".to_string(), "touch ok.txt".to_string());
+        config.add_step("pre-commit".to_string(), "bad".to_string(), "exit 4".to_string());
+        config.add_step("pre-commit".to_string(), "slow".to_string(), "sleep 0.2 && touch slow.txt".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.parallel = true;
+        hook.max_jobs = Some(1);
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 4);
+        // 다른 단계는 실패와 상관없이 끝까지 실행됨
+        assert!(std::path::Path::new("ok.txt").exists());
+        assert!(std::path::Path::new("slow.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_steps_with_conditions() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "needs-file".to_string(), "touch needs-file.txt".to_string());
+        config.add_step("pre-commit".to_string(), "skipped".to_string(), "touch skipped.txt".to_string());
+        config.add_step("pre-commit".to_string(), "no-merge".to_string(), "touch no-merge.txt".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.commands[0].when = Some(Condition {
+            exists: vec!["missing.txt".to_string()],
+            ..Condition::default()
+        });
+        hook.commands[1].skip = Some(Condition {
+            exists: vec![".git".to_string()],
+            ..Condition::default()
+        });
+        hook.commands[2].when = Some(Condition {
+            merge: Some(false),
+            rebase: Some(false),
+            ..Condition::default()
+        });
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions { explain: true })
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert!(!std::path::Path::new("needs-file.txt").exists());
+        assert!(!std::path::Path::new("skipped.txt").exists());
+        assert!(std::path::Path::new("no-merge.txt").exists());
+
+        // merge 중이면 `merge: false` 단계는 건너뜀
+        std::fs::remove_file("no-merge.txt").unwrap();
+        std::fs::write(".git/MERGE_HEAD", "0000000000000000000000000000000000000000\n").unwrap();
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert!(!std::path::Path::new("no-merge.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_reuses_cached_results() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        tokio::fs::write("a.rs", "one\n").await.unwrap();
+        tokio::process::Command::new("git")
+            .args(["add", "a.rs"])
+            .output()
+            .await
+            .unwrap();
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "lint".to_string(), "echo lint >> .git/runs".to_string());
+        config.add_step("pre-commit".to_string(), "flaky".to_string(), "echo flaky >> .git/runs; exit 1".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.stash = true;
+        hook.commands[0].cache = Some(Default::default());
+        hook.commands[1].cache = Some(Default::default());
+        config.save().await.unwrap();
+
+        let runs = || async { tokio::fs::read_to_string(".git/runs").await.unwrap() };
+        let context = HookContext::new("pre-commit", &[]);
+        assert_eq!(HookRunner::run(&context, RunOptions::default()).await.unwrap(), 1);
+        assert_eq!(runs().await, "lint\nflaky\n");
+
+        // 성공한 단계만 재사용하고 실패한 단계는 다시 실행
+        assert_eq!(HookRunner::run(&context, RunOptions::default()).await.unwrap(), 1);
+        assert_eq!(runs().await, "lint\nflaky\nflaky\n");
+
+        // staged 내용이 바뀌면 다시 실행
+        tokio::fs::write("a.rs", "two\n").await.unwrap();
+        tokio::process::Command::new("git")
+            .args(["add", "a.rs"])
+            .output()
+            .await
+            .unwrap();
+        assert_eq!(HookRunner::run(&context, RunOptions::default()).await.unwrap(), 1);
+        assert_eq!(runs().await, "lint\nflaky\nflaky\nlint\nflaky\n");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_step_timeout_kills_process_group() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step(
+            "pre-push".to_string(),
+            "hang".to_string(),
+            "(sleep 2 && touch orphan.txt) & sleep 30; touch late.txt".to_string(),
+        );
+        config.add_step("pre-push".to_string(), "never".to_string(), "touch never.txt".to_string());
+        config.hooks.get_mut("pre-push").unwrap().commands[0].timeout = Some(1);
+        config.save().await.unwrap();
+
+        let started = std::time::Instant::now();
+        let code = HookRunner::run(&HookContext::new("pre-push", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, TIMEOUT_EXIT_CODE);
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(!std::path::Path::new("never.txt").exists());
+
+        // 백그라운드 자식까지 함께 종료됨
+        tokio::time::sleep(Duration::from_secs(2)).await;
+        assert!(!std::path::Path::new("orphan.txt").exists());
+        assert!(!std::path::Path::new("late.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_parallel_hook_timeout() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "fast".to_string(), "touch fast.txt".to_string());
+        config.add_step("pre-commit".to_string(), "hang".to_string(), "echo started; sleep 30".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.parallel = true;
+        hook.timeout = Some(1);
+        config.save().await.unwrap();
+
+        let started = std::time::Instant::now();
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, TIMEOUT_EXIT_CODE);
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(std::path::Path::new("fast.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_parallel_waits_for_other_steps_on_error() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        // commit-msg 밖에서 쓴 conventional-commits는 메시지 파일이 없어 오류
+        let mut broken = HookCommand::new("broken".to_string(), String::new());
+        broken.builtin = Some(crate::checks::Builtin::ConventionalCommits(Default::default()));
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "slow".to_string(), "sleep 1; touch slow.txt".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.commands.insert(0, broken);
+        hook.parallel = true;
+        hook.max_jobs = Some(2);
+        hook.timeout = Some(10);
+        config.save().await.unwrap();
+
+        let result = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default()).await;
+        assert!(result.is_err());
+        // 오류를 보고하기 전에 다른 단계가 끝남
+        assert!(std::path::Path::new("slow.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_timeout_sends_sigkill_after_grace_period() {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg("trap '' TERM; sleep 30");
+        let timeout = Some(Duration::from_millis(200));
+        let mut child = HookRunner::spawn(command, &HookContext::default(), timeout)
+            .await
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let status = HookRunner::wait(&mut child, timeout, &Interrupt::none())
+            .await
+            .unwrap();
+        assert!(status.is_none());
+        assert!(started.elapsed() >= KILL_GRACE_PERIOD);
+        assert!(started.elapsed() < KILL_GRACE_PERIOD + Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_glob_step_with_staged_files() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        for file in ["a.rs", "b.rs", "notes.md", "unstaged.rs"] {
+            tokio::fs::write(file, "content").await.unwrap();
+        }
+        tokio::process::Command::new("git")
+            .args(["add", "a.rs", "b.rs", "notes.md"])
+            .output()
+            .await
+            .unwrap();
+
+        let mut config = Config::default();
+        config.add_step("pre-commit".to_string(), "rs".to_string(), "echo >> rs.txt".to_string());
+        config.add_step("pre-commit".to_string(), "css".to_string(), "touch css.txt".to_string());
+        let hook = config.hooks.get_mut("pre-commit").unwrap();
+        hook.get_command_mut("rs").unwrap().glob = vec!["*.rs".to_string()];
+        hook.get_command_mut("css").unwrap().glob = vec!["*.css".to_string()];
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+
+        // 일치하는 staged 파일만 인자로 전달됨
+        let received = tokio::fs::read_to_string("rs.txt").await.unwrap();
+        assert_eq!(received, "a.rs b.rs\n");
+        // 일치하는 파일이 없는 단계는 건너뜀
+        assert!(!std::path::Path::new("css.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_with_stash_hides_unstaged_changes() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        for args in [
+            vec!["config", "user.name", "Test User"],
+            vec!["config", "user.email", "test@example.com"],
+        ] {
+            tokio::process::Command::new("git").args(args).output().await.unwrap();
+        }
+
+        tokio::fs::write("a.txt", "one\n").await.unwrap();
+        tokio::fs::write("b.txt", "staged\n").await.unwrap();
+        tokio::process::Command::new("git")
+            .args(["add", "a.txt", "b.txt"])
+            .output()
+            .await
+            .unwrap();
+        tokio::fs::write("a.txt", "one\ntwo\n").await.unwrap();
+
+        let mut config = Config::default();
+        config.add_step(
+            "pre-commit".to_string(),
+            "format".to_string(),
+            "cat a.txt > .git/seen && printf 'STAGED\\n' > b.txt".to_string(),
+        );
+        config.hooks.get_mut("pre-commit").unwrap().stash = true;
+        config.save().await.unwrap();
+        tokio::process::Command::new("git")
+            .args(["add", ".rusky"])
+            .output()
+            .await
+            .unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+
+        // 단계는 staged 내용만 봄
+        assert_eq!(tokio::fs::read_to_string(".git/seen").await.unwrap(), "one\n");
+        // 단계가 수정한 파일은 다시 staged 됨
+        let staged = tokio::process::Command::new("git")
+            .args(["show", ":b.txt"])
+            .output()
+            .await
+            .unwrap();
+        assert_eq!(String::from_utf8(staged.stdout).unwrap(), "STAGED\n");
+        // unstaged 변경은 복원됨
+        assert_eq!(tokio::fs::read_to_string("a.txt").await.unwrap(), "one\ntwo\n");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_restores_unstaged_changes_when_restage_fails() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        tokio::fs::write("a.txt", "staged\n").await.unwrap();
+        tokio::fs::write("b.txt", "one\n").await.unwrap();
+        tokio::process::Command::new("git")
+            .args(["add", "a.txt", "b.txt"])
+            .output()
+            .await
+            .unwrap();
+        tokio::fs::write("b.txt", "one\ntwo\n").await.unwrap();
+
+        // 단계가 staged 파일을 고친 뒤 index를 잠가 `git add`가 실패함
+        let mut config = Config::default();
+        config.add_step(
+            "pre-commit".to_string(),
+            "format".to_string(),
+            "printf 'FIXED\\n' > a.txt && touch .git/index.lock".to_string(),
+        );
+        config.hooks.get_mut("pre-commit").unwrap().stash = true;
+        config.save().await.unwrap();
+
+        let result = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default()).await;
+        assert!(result.is_err());
+        std::fs::remove_file(".git/index.lock").unwrap();
+
+        // 숨겨 둔 unstaged 변경과 단계가 고친 내용이 모두 작업 트리에 남음
+        assert_eq!(tokio::fs::read_to_string("b.txt").await.unwrap(), "one\ntwo\n");
+        assert_eq!(tokio::fs::read_to_string("a.txt").await.unwrap(), "FIXED\n");
+        assert!(!UnstagedStash::is_pending().await.unwrap());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    async fn install_original_hook(hook_name: &str, script: &str) {
+        let hook_path = Git::get_hook_path(hook_name).await.unwrap();
+        tokio::fs::write(&hook_path, script).await.unwrap();
+        let mut perms = tokio::fs::metadata(&hook_path).await.unwrap().permissions();
+        std::os::unix::fs::PermissionsExt::set_mode(&mut perms, 0o755);
+        tokio::fs::set_permissions(&hook_path, perms).await.unwrap();
+        HookManager::create_hook_file(hook_name).await.unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_chains_original_hook() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        install_original_hook("pre-push", "#!/bin/sh\necho \"original $1\" >> order.txt\n").await;
+
+        let mut config = Config::default();
+        config.add_hook("pre-push".to_string(), "echo rusky >> order.txt".to_string());
+        config.hooks.get_mut("pre-push").unwrap().chain = Some(ChainMode::Before);
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-push", &["origin".to_string()]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert_eq!(
+            tokio::fs::read_to_string("order.txt").await.unwrap(),
+            "original origin\nrusky\n"
+        );
+
+        tokio::fs::remove_file("order.txt").await.unwrap();
+        config.hooks.get_mut("pre-push").unwrap().chain = Some(ChainMode::After);
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-push", &["origin".to_string()]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert_eq!(
+            tokio::fs::read_to_string("order.txt").await.unwrap(),
+            "rusky\noriginal origin\n"
+        );
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_fails_when_original_hook_fails() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        install_original_hook("pre-commit", "#!/bin/sh\nexit 2\n").await;
+
+        let mut config = Config::default();
+        config.add_hook("pre-commit".to_string(), "touch ran.txt".to_string());
+        config.hooks.get_mut("pre-commit").unwrap().chain = Some(ChainMode::Before);
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 2);
+        assert!(!std::path::Path::new("ran.txt").exists());
+
+        // chain이 없으면 원래 hook은 실행하지 않음
+        config.hooks.get_mut("pre-commit").unwrap().chain = None;
+        config.save().await.unwrap();
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+
+        // 모르는 chain 값이면 경고만 하고 원래 hook은 실행하지 않음
+        config.hooks.get_mut("pre-commit").unwrap().chain =
+            Some(ChainMode::Unknown(Value::from("beforee")));
+        config.save().await.unwrap();
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_skips_non_executable_original_hook() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+        install_original_hook("pre-commit", "#!/bin/sh\nexit 2\n").await;
+        let backup_path = HookManager::get_backup_path("pre-commit").await.unwrap();
+        let mut perms = tokio::fs::metadata(&backup_path).await.unwrap().permissions();
+        perms.set_mode(0o644);
+        tokio::fs::set_permissions(&backup_path, perms).await.unwrap();
+
+        let mut config = Config::default();
+        config.add_hook("pre-commit".to_string(), "touch ran.txt".to_string());
+        config.hooks.get_mut("pre-commit").unwrap().chain = Some(ChainMode::Before);
+        config.save().await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("pre-commit", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        assert!(std::path::Path::new("ran.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_passes_hook_arguments() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_hook("commit-msg".to_string(), "cp \"$1\" copied.txt".to_string());
+        config.save().await.unwrap();
+        tokio::fs::write("MSG", "feat: hello").await.unwrap();
+
+        let code = HookRunner::run(&HookContext::new("commit-msg", &["MSG".to_string()]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+        let copied = tokio::fs::read_to_string("copied.txt").await.unwrap();
+        assert_eq!(copied, "feat: hello");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_expands_placeholders() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_hook("commit-msg".to_string(), "cp {msg_file} copied.txt".to_string());
+        config.save().await.unwrap();
+        tokio::fs::write("MY MSG", "fix: bug").await.unwrap();
+
+        let context = HookContext::new("commit-msg", &["MY MSG".to_string()]);
+        let code = HookRunner::run(&context, RunOptions::default()).await.unwrap();
+        assert_eq!(code, 0);
+        assert_eq!(tokio::fs::read_to_string("copied.txt").await.unwrap(), "fix: bug");
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_passes_stdin_to_every_step() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut config = Config::default();
+        config.add_step("pre-push".to_string(), "first".to_string(), "cat > first.txt".to_string());
+        config.add_step("pre-push".to_string(), "second".to_string(), "cat > second.txt".to_string());
+        config.add_step("pre-push".to_string(), "refs".to_string(), "echo {remote} {refs} > refs.txt".to_string());
+        config.save().await.unwrap();
+
+        let stdin = "refs/heads/main abc refs/heads/main def\n";
+        let context = HookContext::new("pre-push", &["origin".to_string(), "url".to_string()])
+            .with_stdin(stdin);
+        for parallel in [false, true] {
+            let mut config = Config::load().await.unwrap();
+            config.hooks.get_mut("pre-push").unwrap().parallel = parallel;
+            config.save().await.unwrap();
+
+            let code = HookRunner::run(&context, RunOptions::default()).await.unwrap();
+            assert_eq!(code, 0);
+            assert_eq!(tokio::fs::read_to_string("first.txt").await.unwrap(), stdin);
+            assert_eq!(tokio::fs::read_to_string("second.txt").await.unwrap(), stdin);
+            assert_eq!(
+                tokio::fs::read_to_string("refs.txt").await.unwrap(),
+                "origin refs/heads/main\n"
+            );
+        }
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_builtin_step() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut step = HookCommand::new("commitlint".to_string(), String::new());
+        step.builtin = Some(crate::checks::Builtin::ConventionalCommits(Default::default()));
+        let mut config = Config::default();
+        config.hooks.entry("commit-msg".to_string()).or_default().commands.push(step);
+        config.save().await.unwrap();
+
+        let context = HookContext::new("commit-msg", &["MSG".to_string()]);
+        tokio::fs::write("MSG", "feat: add builtin checks\n").await.unwrap();
+        assert_eq!(HookRunner::run(&context, RunOptions::default()).await.unwrap(), 0);
+
+        tokio::fs::write("MSG", "added builtin checks\n").await.unwrap();
+        assert_eq!(HookRunner::run(&context, RunOptions::default()).await.unwrap(), 1);
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_builtin_step_timeout() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        let mut step = HookCommand::new("commitlint".to_string(), String::new());
+        step.builtin = Some(crate::checks::Builtin::ConventionalCommits(Default::default()));
+        step.timeout = Some(1);
+        let mut config = Config::default();
+        config.hooks.entry("commit-msg".to_string()).or_default().commands.push(step);
+        config.save().await.unwrap();
+
+        // 쓰는 쪽이 열릴 때까지 메시지 파일 읽기가 멈춤
+        let fifo = std::ffi::CString::new("MSG").unwrap();
+        assert_eq!(unsafe { libc::mkfifo(fifo.as_ptr(), 0o644) }, 0);
+
+        let context = HookContext::new("commit-msg", &["MSG".to_string()]);
+        let started = std::time::Instant::now();
+        let code = HookRunner::run(&context, RunOptions::default()).await.unwrap();
+        assert_eq!(code, TIMEOUT_EXIT_CODE);
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        // 멈춰 있는 읽기 작업 풀어 주기
+        drop(std::fs::OpenOptions::new().write(true).open("MSG").unwrap());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_run_unconfigured_hook() {
+        let (_temp_dir, original_dir) = setup_test_git_repo().await;
+
+        Config::default().save().await.unwrap();
+        let code = HookRunner::run(&HookContext::new("post-merge", &[]), RunOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(code, 0);
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
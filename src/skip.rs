@@ -0,0 +1,125 @@
+//! 환경 변수로 hook과 단계 건너뛰기 (`RUSKY=0`, `RUSKY_SKIP`, CI 감지)
+
+/// 모든 hook을 끄는 환경 변수 (`RUSKY=0`)
+const RUSKY_ENV: &str = "RUSKY";
+
+/// 건너뛸 단계 이름을 쉼표로 구분해 지정하는 환경 변수
+const RUSKY_SKIP_ENV: &str = "RUSKY_SKIP";
+
+/// CI 서비스와 그 서비스가 설정하는 환경 변수
+const CI_SERVICES: &[(&str, &str)] = &[
+    ("GITHUB_ACTIONS", "GitHub Actions"),
+    ("GITLAB_CI", "GitLab CI"),
+    ("CIRCLECI", "CircleCI"),
+    ("TRAVIS", "Travis CI"),
+    ("BUILDKITE", "Buildkite"),
+    ("JENKINS_URL", "Jenkins"),
+    ("TF_BUILD", "Azure Pipelines"),
+    ("BITBUCKET_BUILD_NUMBER", "Bitbucket Pipelines"),
+    ("TEAMCITY_VERSION", "TeamCity"),
+    ("CODEBUILD_BUILD_ID", "AWS CodeBuild"),
+    ("DRONE", "Drone"),
+    ("APPVEYOR", "AppVeyor"),
+];
+
+/// 실행 환경에서 읽은 건너뛰기 설정
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Skip {
+    /// `RUSKY=0`으로 모든 hook이 꺼졌는지 여부
+    pub disabled: bool,
+    /// `RUSKY_SKIP`에 지정된 단계 이름
+    pub steps: Vec<String>,
+    /// 감지된 CI 서비스 이름 (CI가 아니면 None)
+    pub ci: Option<String>,
+}
+
+impl Skip {
+    /// 현재 프로세스의 환경 변수 읽기
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let disabled = var(RUSKY_ENV).is_some_and(|value| is_off(&value));
+
+        let steps = var(RUSKY_SKIP_ENV)
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        let ci = CI_SERVICES
+            .iter()
+            .find(|(name, _)| var(name).is_some_and(|value| !value.is_empty() && !is_off(&value)))
+            .map(|(_, service)| service.to_string())
+            .or_else(|| {
+                // 서비스를 알 수 없어도 대부분의 CI는 `CI=true`를 설정
+                var("CI")
+                    .filter(|value| !value.is_empty() && !is_off(value))
+                    .map(|_| "CI".to_string())
+            });
+
+        Self {
+            disabled,
+            steps,
+            ci,
+        }
+    }
+
+    /// `RUSKY_SKIP`으로 건너뛸 단계인지 확인
+    pub fn skips_step(&self, name: &str) -> bool {
+        self.steps.iter().any(|step| step == name)
+    }
+}
+
+/// `0`, `false`, `off`, `no`처럼 끄는 값인지 확인
+fn is_off(value: &str) -> bool {
+    matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "0" | "false" | "off" | "no"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn skip(vars: &[(&str, &str)]) -> Skip {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Skip::from_vars(|name| vars.get(name).cloned())
+    }
+
+    #[test]
+    fn test_rusky_disabled() {
+        assert!(skip(&[("RUSKY", "0")]).disabled);
+        assert!(skip(&[("RUSKY", "false")]).disabled);
+        assert!(!skip(&[("RUSKY", "1")]).disabled);
+        assert!(!skip(&[]).disabled);
+    }
+
+    #[test]
+    fn test_rusky_skip_steps() {
+        let skip = skip(&[("RUSKY_SKIP", " lint, test ,,")]);
+        assert_eq!(skip.steps, vec!["lint", "test"]);
+        assert!(skip.skips_step("lint"));
+        assert!(!skip.skips_step("format"));
+    }
+
+    #[test]
+    fn test_detect_ci() {
+        assert_eq!(skip(&[]).ci, None);
+        assert_eq!(
+            skip(&[("GITHUB_ACTIONS", "true"), ("CI", "true")]).ci.as_deref(),
+            Some("GitHub Actions")
+        );
+        assert_eq!(skip(&[("CI", "1")]).ci.as_deref(), Some("CI"));
+        assert_eq!(skip(&[("CI", "false")]).ci, None);
+        assert_eq!(skip(&[("TRAVIS", "")]).ci, None);
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Result};
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+
+/// 한 번의 명령어 실행에 넘길 파일 경로 인자의 최대 길이 (바이트)
+pub const MAX_ARGS_LENGTH: usize = 64 * 1024;
+
+/// 단계의 glob 패턴과 파일 경로를 비교하는 matcher
+///
+/// `/`가 없는 패턴(`*.rs`)은 lint-staged처럼 파일 이름에만 비교하고,
+/// `/`가 있는 패턴(`src/**/*.rs`)은 저장소 루트 기준 전체 경로와 비교합니다.
+pub struct FileMatcher {
+    paths: GlobSet,
+    file_names: GlobSet,
+}
+
+impl FileMatcher {
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let mut paths = GlobSetBuilder::new();
+        let mut file_names = GlobSetBuilder::new();
+
+        for pattern in patterns {
+            if pattern.contains('/') {
+                let glob = GlobBuilder::new(pattern.trim_start_matches("./"))
+                    .literal_separator(true)
+                    .build()
+                    .map_err(|e| anyhow!("Invalid glob pattern {}: {}", pattern, e))?;
+                paths.add(glob);
+            } else {
+                let glob = Glob::new(pattern)
+                    .map_err(|e| anyhow!("Invalid glob pattern {}: {}", pattern, e))?;
+                file_names.add(glob);
+            }
+        }
+
+        Ok(Self {
+            paths: paths.build()?,
+            file_names: file_names.build()?,
+        })
+    }
+
+    pub fn matches(&self, path: &str) -> bool {
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        self.paths.is_match(path) || self.file_names.is_match(file_name)
+    }
+
+    /// 패턴과 일치하는 파일만 골라내기
+    pub fn filter(&self, files: &[String]) -> Vec<String> {
+        files
+            .iter()
+            .filter(|file| self.matches(file))
+            .cloned()
+            .collect()
+    }
+}
+
+/// 인자 길이 제한을 넘지 않도록 파일 목록을 여러 묶음으로 나누기
+pub fn chunk_files(files: &[String], max_length: usize) -> Vec<Vec<String>> {
+    let mut chunks = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut length = 0;
+
+    for file in files {
+        // 구분용 공백 포함
+        let file_length = file.len() + 1;
+        if !current.is_empty() && length + file_length > max_length {
+            chunks.push(std::mem::take(&mut current));
+            length = 0;
+        }
+        current.push(file.clone());
+        length += file_length;
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|path| path.to_string()).collect()
+    }
+
+    #[test]
+    fn test_file_name_patterns_match_any_directory() {
+        let matcher = FileMatcher::new(&files(&["*.rs"])).unwrap();
+        assert!(matcher.matches("main.rs"));
+        assert!(matcher.matches("src/cli/mod.rs"));
+        assert!(!matcher.matches("README.md"));
+    }
+
+    #[test]
+    fn test_path_patterns_match_from_root() {
+        let matcher = FileMatcher::new(&files(&["src/**/*.ts", "./docs/*.md"])).unwrap();
+        assert!(matcher.matches("src/index.ts"));
+        assert!(matcher.matches("src/app/main.ts"));
+        assert!(!matcher.matches("test/main.ts"));
+        assert!(matcher.matches("docs/guide.md"));
+        assert!(!matcher.matches("docs/api/guide.md"));
+    }
+
+    #[test]
+    fn test_brace_patterns_and_filter() {
+        let matcher = FileMatcher::new(&files(&["*.{js,ts}"])).unwrap();
+        let staged = files(&["a.js", "b.ts", "c.css", "lib/d.js"]);
+        assert_eq!(matcher.filter(&staged), files(&["a.js", "b.ts", "lib/d.js"]));
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        assert!(FileMatcher::new(&files(&["src/[.rs"])).is_err());
+    }
+
+    #[test]
+    fn test_chunk_files() {
+        let staged = files(&["aaaa", "bbbb", "cccc", "dddd", "eeee"]);
+        let chunks = chunk_files(&staged, 10);
+        assert_eq!(
+            chunks,
+            vec![files(&["aaaa", "bbbb"]), files(&["cccc", "dddd"]), files(&["eeee"])]
+        );
+
+        assert_eq!(chunk_files(&staged, MAX_ARGS_LENGTH), vec![staged.clone()]);
+        assert!(chunk_files(&[], 10).is_empty());
+
+        // 제한보다 긴 파일 하나는 단독 묶음이 됨
+        let long = files(&["a-very-long-file-name.txt", "b"]);
+        assert_eq!(chunk_files(&long, 5), vec![files(&["a-very-long-file-name.txt"]), files(&["b"])]);
+    }
+}
@@ -0,0 +1,321 @@
+use anyhow::{anyhow, Result};
+use colored::*;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+use crate::git::Git;
+
+/// 숨겨 둔 변경을 보관하는 Git 디렉토리 안의 경로
+const STATE_DIR: &str = "rusky-stash";
+const PATCH_FILE: &str = "unstaged.patch";
+const UNTRACKED_DIR: &str = "untracked";
+const UNTRACKED_LIST: &str = "untracked.list";
+const BACKUP_FILE: &str = "backup";
+
+/// hook 실행 동안 숨겨 둔 unstaged 변경 (및 선택적으로 untracked 파일)
+///
+/// 상태는 `.git/rusky-stash`에 기록되므로 프로세스가 중간에 종료되더라도
+/// `rusky restore`로 복구할 수 있습니다.
+pub struct UnstagedStash {
+    state_dir: PathBuf,
+    staged_files: Vec<String>,
+    has_patch: bool,
+    untracked: Vec<String>,
+    backup: Option<String>,
+}
+
+impl UnstagedStash {
+    /// 상태 디렉토리 경로
+    pub async fn state_dir() -> Result<PathBuf> {
+        Git::get_git_path(STATE_DIR).await
+    }
+
+    /// 중단된 실행이 남긴 상태가 있는지 확인
+    pub async fn is_pending() -> Result<bool> {
+        Ok(Self::state_dir().await?.exists())
+    }
+
+    /// unstaged 변경을 patch로 저장한 뒤 작업 트리를 index 내용으로 되돌리기
+    pub async fn save(include_untracked: bool) -> Result<Self> {
+        let state_dir = Self::state_dir().await?;
+        if state_dir.exists() {
+            return Err(anyhow!(
+                "A previous rusky run was interrupted and left hidden changes in {}. Run 'rusky restore' first.",
+                state_dir.display()
+            ));
+        }
+        fs::create_dir_all(&state_dir).await?;
+
+        let staged_files = Git::get_staged_files().await?;
+
+        // 복구용 백업 (stash 목록에는 추가하지 않음)
+        let backup = Git::create_stash().await?;
+        if let Some(backup) = &backup {
+            fs::write(state_dir.join(BACKUP_FILE), backup).await?;
+        }
+
+        let patch = Git::get_unstaged_patch().await?;
+        let has_patch = !patch.is_empty();
+        if has_patch {
+            fs::write(state_dir.join(PATCH_FILE), &patch).await?;
+            Git::checkout_files(&Git::get_unstaged_files().await?).await?;
+        }
+
+        let untracked = if include_untracked {
+            let untracked = Git::get_untracked_files().await?;
+            fs::write(state_dir.join(UNTRACKED_LIST), untracked.join("\0")).await?;
+            for file in &untracked {
+                move_file(Path::new(file), &state_dir.join(UNTRACKED_DIR).join(file)).await?;
+            }
+            untracked
+        } else {
+            Vec::new()
+        };
+
+        if has_patch || !untracked.is_empty() {
+            eprintln!("{}", "📦 rusky > hid unstaged changes".dimmed());
+        }
+
+        Ok(Self {
+            state_dir,
+            staged_files,
+            has_patch,
+            untracked,
+            backup,
+        })
+    }
+
+    /// 단계가 수정한 staged 파일을 다시 index에 추가
+    pub async fn restage(&self) -> Result<Vec<String>> {
+        let modified: Vec<String> = Git::get_unstaged_files()
+            .await?
+            .into_iter()
+            .filter(|file| self.staged_files.contains(file))
+            .collect();
+
+        Git::add_files(&modified).await?;
+        Ok(modified)
+    }
+
+    /// 숨겨 둔 변경을 작업 트리에 되돌리기
+    ///
+    /// 단계가 실패했다면 단계가 만든 변경은 버리고 원래 상태로 돌아갑니다.
+    pub async fn restore(self, succeeded: bool) -> Result<()> {
+        if !succeeded {
+            Git::checkout_files(&Git::get_unstaged_files().await?).await?;
+        }
+
+        Self::restore_state(&self.state_dir, self.has_patch, &self.untracked, self.backup)
+            .await?;
+
+        if self.has_patch || !self.untracked.is_empty() {
+            eprintln!("{}", "📦 rusky > restored unstaged changes".dimmed());
+        }
+        Ok(())
+    }
+
+    /// 중단된 실행이 남긴 상태에서 숨겨 둔 변경 복구
+    pub async fn recover() -> Result<bool> {
+        let state_dir = Self::state_dir().await?;
+        if !state_dir.exists() {
+            return Ok(false);
+        }
+
+        let has_patch = state_dir.join(PATCH_FILE).exists();
+        let untracked = match fs::read_to_string(state_dir.join(UNTRACKED_LIST)).await {
+            Ok(list) => list
+                .split('\0')
+                .filter(|file| !file.is_empty())
+                .map(String::from)
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+        let backup = fs::read_to_string(state_dir.join(BACKUP_FILE))
+            .await
+            .ok()
+            .map(|hash| hash.trim().to_string());
+
+        Self::restore_state(&state_dir, has_patch, &untracked, backup).await?;
+        Ok(true)
+    }
+
+    async fn restore_state(
+        state_dir: &Path,
+        has_patch: bool,
+        untracked: &[String],
+        backup: Option<String>,
+    ) -> Result<()> {
+        for file in untracked {
+            let hidden = state_dir.join(UNTRACKED_DIR).join(file);
+            if hidden.exists() && !Path::new(file).exists() {
+                move_file(&hidden, Path::new(file)).await?;
+            }
+        }
+
+        let patch_path = state_dir.join(PATCH_FILE);
+        if has_patch && !Git::apply_patch(&patch_path).await? {
+            let mut message = format!(
+                "Failed to restore unstaged changes: they conflict with changes made by the hook.\nYour changes are saved in {}; apply them with 'git apply --3way {}'",
+                patch_path.display(),
+                patch_path.display()
+            );
+            if let Some(backup) = backup {
+                message.push_str(&format!(" or 'git stash apply {}'", backup));
+            }
+            message.push_str(&format!(
+                ", then remove {}.",
+                state_dir.display()
+            ));
+            return Err(anyhow!(message));
+        }
+
+        fs::remove_dir_all(state_dir).await?;
+        Ok(())
+    }
+}
+
+/// 필요한 상위 디렉토리를 만들면서 파일 이동
+async fn move_file(from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    fs::rename(from, to).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::env;
+    use tempfile::TempDir;
+
+    async fn git(args: &[&str]) {
+        let output = tokio::process::Command::new("git")
+            .args(args)
+            .output()
+            .await
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    async fn setup_partially_staged_repo() -> (TempDir, std::path::PathBuf) {
+        let temp_dir = TempDir::new().unwrap();
+        let original_dir = env::current_dir().unwrap();
+        env::set_current_dir(temp_dir.path()).unwrap();
+
+        git(&["init"]).await;
+        git(&["config", "user.name", "Test User"]).await;
+        git(&["config", "user.email", "test@example.com"]).await;
+
+        fs::write("a.txt", "a\nb\nc\nd\ne\nf\ng\n").await.unwrap();
+        git(&["add", "a.txt"]).await;
+        git(&["commit", "-m", "initial"]).await;
+
+        // staged 변경과 unstaged 변경이 섞인 파일
+        fs::write("a.txt", "a\nb\nc\nd\ne\nf\ng\nstaged\n").await.unwrap();
+        git(&["add", "a.txt"]).await;
+        fs::write("a.txt", "a\nb\nc\nd\ne\nf\ng\nstaged\nunstaged\n").await.unwrap();
+        fs::write("untracked.txt", "untracked").await.unwrap();
+
+        (temp_dir, original_dir)
+    }
+
+    async fn index_content(path: &str) -> String {
+        let output = tokio::process::Command::new("git")
+            .args(["show", &format!(":{}", path)])
+            .output()
+            .await
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_save_hides_unstaged_and_restore_brings_back() {
+        let (_temp_dir, original_dir) = setup_partially_staged_repo().await;
+
+        let stash = UnstagedStash::save(true).await.unwrap();
+        assert_eq!(
+            fs::read_to_string("a.txt").await.unwrap(),
+            "a\nb\nc\nd\ne\nf\ng\nstaged\n"
+        );
+        assert!(!Path::new("untracked.txt").exists());
+        assert!(UnstagedStash::is_pending().await.unwrap());
+
+        stash.restore(true).await.unwrap();
+        assert_eq!(
+            fs::read_to_string("a.txt").await.unwrap(),
+            "a\nb\nc\nd\ne\nf\ng\nstaged\nunstaged\n"
+        );
+        assert!(Path::new("untracked.txt").exists());
+        assert!(!UnstagedStash::is_pending().await.unwrap());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_restage_modified_files() {
+        let (_temp_dir, original_dir) = setup_partially_staged_repo().await;
+
+        let stash = UnstagedStash::save(false).await.unwrap();
+        // 포매터가 staged 파일을 수정한 상황
+        fs::write("a.txt", "A\nb\nc\nd\ne\nf\ng\nstaged\n").await.unwrap();
+
+        let restaged = stash.restage().await.unwrap();
+        assert_eq!(restaged, vec!["a.txt".to_string()]);
+        stash.restore(true).await.unwrap();
+
+        assert_eq!(index_content("a.txt").await, "A\nb\nc\nd\ne\nf\ng\nstaged\n");
+        assert_eq!(
+            fs::read_to_string("a.txt").await.unwrap(),
+            "A\nb\nc\nd\ne\nf\ng\nstaged\nunstaged\n"
+        );
+        // untracked 파일은 숨기지 않았으므로 그대로 남아 있음
+        assert!(Path::new("untracked.txt").exists());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_restore_after_failure_discards_hook_changes() {
+        let (_temp_dir, original_dir) = setup_partially_staged_repo().await;
+
+        let stash = UnstagedStash::save(false).await.unwrap();
+        fs::write("a.txt", "broken\n").await.unwrap();
+        stash.restore(false).await.unwrap();
+
+        assert_eq!(index_content("a.txt").await, "a\nb\nc\nd\ne\nf\ng\nstaged\n");
+        assert_eq!(
+            fs::read_to_string("a.txt").await.unwrap(),
+            "a\nb\nc\nd\ne\nf\ng\nstaged\nunstaged\n"
+        );
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recover_interrupted_run() {
+        let (_temp_dir, original_dir) = setup_partially_staged_repo().await;
+
+        // restore 없이 종료된 상황
+        let stash = UnstagedStash::save(true).await.unwrap();
+        drop(stash);
+
+        // 남은 상태가 있으면 다시 숨기지 않음
+        assert!(UnstagedStash::save(true).await.is_err());
+
+        assert!(UnstagedStash::recover().await.unwrap());
+        assert_eq!(
+            fs::read_to_string("a.txt").await.unwrap(),
+            "a\nb\nc\nd\ne\nf\ng\nstaged\nunstaged\n"
+        );
+        assert!(Path::new("untracked.txt").exists());
+        assert!(!UnstagedStash::recover().await.unwrap());
+
+        env::set_current_dir(original_dir).unwrap();
+    }
+}
@@ -13,18 +13,18 @@ fn setup_test_env() -> (TempDir, std::path::PathBuf) {
     
     // Git 저장소 초기화
     Command::new("git")
-        .args(&["init"])
+        .args(["init"])
         .output()
         .unwrap();
     
     // Git 사용자 설정 (테스트용)
     Command::new("git")
-        .args(&["config", "user.name", "Test User"])
+        .args(["config", "user.name", "Test User"])
         .output()
         .unwrap();
     
     Command::new("git")
-        .args(&["config", "user.email", "test@example.com"])
+        .args(["config", "user.email", "test@example.com"])
         .output()
         .unwrap();
     
@@ -140,22 +140,308 @@ fn test_rusky_hook_execution() {
     
     // Git add
     Command::new("git")
-        .args(&["add", "test.txt"])
+        .args(["add", "test.txt"])
         .output()
         .unwrap();
     
     // Git commit (pre-commit hook이 실행되어야 함)
     let output = Command::new("git")
-        .args(&["commit", "-m", "Test commit"])
+        .args(["commit", "-m", "Test commit"])
         .output()
         .unwrap();
     
     assert!(output.status.success());
     
+    // git은 hook의 stdout을 stderr로 전달함
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("🐺 rusky > pre-commit"));
+    assert!(stderr.contains("Hook executed successfully"));
+    assert!(stderr.contains("✅ rusky > pre-commit completed"));
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_hook_reads_config_at_runtime() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    run_rusky_command(&["init"]);
+    run_rusky_command(&["add", "pre-commit", "echo 'old command'"]);
+    
+    // install 없이 설정 파일만 수정
+    std::fs::write(
+        ".rusky/config.json",
+        r#"{"hooks": {"pre-commit": "echo 'new command'"}, "version": "0.1.0"}"#,
+    )
+    .unwrap();
+    
+    std::fs::write("test.txt", "test content").unwrap();
+    Command::new("git")
+        .args(["add", "test.txt"])
+        .output()
+        .unwrap();
+    
+    let output = Command::new("git")
+        .args(["commit", "-m", "Test commit"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("new command"));
+    assert!(!stderr.contains("old command"));
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_run_failure_exit_code() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    run_rusky_command(&["init"]);
+    run_rusky_command(&["add", "pre-push", "exit 7"]);
+    
+    let output = run_rusky_command(&["run", "pre-push", "origin", "git@example.com:repo.git"]);
+    assert_eq!(output.status.code(), Some(7));
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_run_forwards_stdin() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    run_rusky_command(&["init"]);
+    run_rusky_command(&["add", "pre-push", "cat > first.txt", "--name", "first"]);
+    run_rusky_command(&["add", "pre-push", "cat > second.txt", "--name", "second"]);
+    
+    let cargo_bin = env::var("CARGO_BIN_EXE_rusky")
+        .unwrap_or_else(|_| "./target/debug/rusky".to_string());
+    let mut child = Command::new(cargo_bin)
+        .args(["run", "pre-push", "origin", "git@example.com:repo.git"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    let refs = "refs/heads/main 1111 refs/heads/main 2222\n";
+    child.stdin.take().unwrap().write_all(refs.as_bytes()).unwrap();
+    assert!(child.wait().unwrap().success());
+    
+    // 표준 입력은 모든 단계에 전달됨
+    assert_eq!(std::fs::read_to_string("first.txt").unwrap(), refs);
+    assert_eq!(std::fs::read_to_string("second.txt").unwrap(), refs);
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_skip_env() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    run_rusky_command(&["init"]);
+    run_rusky_command(&["add", "pre-push", "touch lint.txt", "--name", "lint"]);
+    run_rusky_command(&["add", "pre-push", "touch test.txt", "--name", "test"]);
+    
+    let cargo_bin = env::var("CARGO_BIN_EXE_rusky")
+        .unwrap_or_else(|_| "./target/debug/rusky".to_string());
+    
+    // RUSKY=0이면 hook 전체를 건너뜀
+    let output = Command::new(&cargo_bin)
+        .args(["run", "pre-push"])
+        .env("RUSKY", "0")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("pre-push skipped (RUSKY=0)"));
+    assert!(!std::path::Path::new("lint.txt").exists());
+    
+    // RUSKY_SKIP에 있는 단계만 건너뜀
+    let output = Command::new(&cargo_bin)
+        .args(["run", "pre-push"])
+        .env("RUSKY_SKIP", "lint")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stderr).unwrap().contains("(skipped by RUSKY_SKIP)"));
+    assert!(!std::path::Path::new("lint.txt").exists());
+    assert!(std::path::Path::new("test.txt").exists());
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_skip_in_ci() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    std::fs::create_dir_all(".rusky").unwrap();
+    std::fs::write(
+        ".rusky/config.json",
+        r#"{"hooks": {"pre-push": {"commands": ["touch ran.txt"], "skip_in_ci": true}}, "version": "0.1.0"}"#,
+    )
+    .unwrap();
+    
+    let cargo_bin = env::var("CARGO_BIN_EXE_rusky")
+        .unwrap_or_else(|_| "./target/debug/rusky".to_string());
+    let output = Command::new(&cargo_bin)
+        .args(["run", "pre-push"])
+        .env("GITHUB_ACTIONS", "true")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("pre-push skipped in CI (GitHub Actions)"));
+    assert!(!std::path::Path::new("ran.txt").exists());
+    
+    // CI가 아니면 실행됨
+    let mut command = Command::new(&cargo_bin);
+    command.args(["run", "pre-push"]);
+    for name in ["CI", "GITHUB_ACTIONS", "GITLAB_CI", "CIRCLECI", "TRAVIS", "BUILDKITE", "JENKINS_URL", "TF_BUILD", "BITBUCKET_BUILD_NUMBER", "TEAMCITY_VERSION", "CODEBUILD_BUILD_ID", "DRONE", "APPVEYOR"] {
+        command.env_remove(name);
+    }
+    assert!(command.output().unwrap().status.success());
+    assert!(std::path::Path::new("ran.txt").exists());
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_run_explain() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    std::fs::create_dir_all(".rusky").unwrap();
+    std::fs::write(
+        ".rusky/config.json",
+        r#"{
+            "hooks": {
+                "pre-commit": {
+                    "commands": [
+                        { "name": "deploy-check", "run": "touch deploy.txt", "when": { "exists": "deploy.yml" } },
+                        { "name": "lint", "run": "touch lint.txt", "skip": { "merge": true, "rebase": true } }
+                    ]
+                }
+            },
+            "version": "0.1.0"
+        }"#,
+    )
+    .unwrap();
+    
+    let output = run_rusky_command(&["run", "pre-commit", "--explain"]);
+    assert!(output.status.success());
+    
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("deploy-check (when: ✗ deploy.yml does not exist)"));
+    assert!(stderr.contains("lint (runs: skip: not a merge; skip: not a rebase)"));
+    assert!(!std::path::Path::new("deploy.txt").exists());
+    assert!(std::path::Path::new("lint.txt").exists());
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_cache_and_clear() {
+    let (_temp_dir, original_dir) = setup_test_env();
+
+    std::fs::create_dir_all(".rusky").unwrap();
+    std::fs::write(
+        ".rusky/config.json",
+        r#"{
+            "hooks": {
+                "pre-commit": {
+                    "commands": [
+                        { "name": "lint", "run": "echo x >> lint.txt", "cache": { "files": "lint.toml" } }
+                    ]
+                }
+            },
+            "version": "0.1.0"
+        }"#,
+    )
+    .unwrap();
+
+    let output = run_rusky_command(&["run", "pre-commit"]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("completed (cache: 0 hit(s), 1 miss(es))"));
+
+    // 입력이 같으면 다시 실행하지 않음
+    let output = run_rusky_command(&["run", "pre-commit"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("lint (cached)"));
+    assert!(stderr.contains("completed (cache: 1 hit(s), 0 miss(es))"));
+    assert_eq!(std::fs::read_to_string("lint.txt").unwrap(), "x\n");
+
+    // 추가 입력 파일이 바뀌면 다시 실행
+    std::fs::write("lint.toml", "strict = true\n").unwrap();
+    assert!(run_rusky_command(&["run", "pre-commit"]).status.success());
+    assert_eq!(std::fs::read_to_string("lint.txt").unwrap(), "x\nx\n");
+
+    let output = run_rusky_command(&["cache", "clear"]);
+    assert!(output.status.success());
     let stdout = String::from_utf8(output.stdout).unwrap();
-    assert!(stdout.contains("🐺 rusky > hook"));
-    assert!(stdout.contains("Hook executed successfully"));
-    assert!(stdout.contains("✅ rusky > hook completed"));
+    assert!(stdout.contains("Removed 2 cached result(s)"));
+
+    assert!(run_rusky_command(&["run", "pre-commit"]).status.success());
+    assert_eq!(std::fs::read_to_string("lint.txt").unwrap(), "x\nx\nx\n");
+
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_post_merge_changed_filter() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    std::fs::create_dir_all(".rusky").unwrap();
+    std::fs::write(
+        ".rusky/config.json",
+        r#"{
+            "hooks": {
+                "post-merge": {
+                    "commands": [
+                        { "name": "install", "run": "echo x >> installed.txt", "when": { "changed": "package-lock.json" } }
+                    ]
+                }
+            },
+            "version": "0.1.0"
+        }"#,
+    )
+    .unwrap();
+    assert!(run_rusky_command(&["install"]).status.success());
+    
+    let git = |args: &[&str]| {
+        let output = Command::new("git").args(args).output().unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+    };
+    std::fs::write("README.md", "readme").unwrap();
+    git(&["add", "README.md"]);
+    git(&["commit", "-q", "-m", "initial"]);
+    git(&["checkout", "-q", "-b", "docs"]);
+    std::fs::write("README.md", "more docs").unwrap();
+    git(&["commit", "-q", "-am", "docs"]);
+    git(&["checkout", "-q", "-b", "deps"]);
+    std::fs::write("package-lock.json", "{}").unwrap();
+    git(&["add", "package-lock.json"]);
+    git(&["commit", "-q", "-m", "deps"]);
+    git(&["checkout", "-q", "-"]);
+    
+    // package-lock.json이 바뀌지 않은 merge에서는 실행되지 않음
+    git(&["checkout", "-q", "-b", "main-copy", "HEAD~1"]);
+    git(&["merge", "-q", "docs"]);
+    assert!(!std::path::Path::new("installed.txt").exists());
+    
+    // package-lock.json이 바뀐 merge에서만 실행됨
+    git(&["merge", "-q", "deps"]);
+    assert_eq!(std::fs::read_to_string("installed.txt").unwrap(), "x\n");
     
     cleanup_test_env(original_dir);
 }
@@ -190,6 +476,97 @@ fn test_rusky_multiple_hooks() {
     cleanup_test_env(original_dir);
 }
 
+#[test]
+#[serial]
+fn test_rusky_import_husky() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    // husky v9 설치 상태 만들기
+    std::fs::create_dir_all(".husky/_").unwrap();
+    std::fs::write(".husky/pre-commit", "echo 'from husky' > husky-ran.txt\n").unwrap();
+    Command::new("git")
+        .args(["config", "core.hooksPath", ".husky/_"])
+        .output()
+        .unwrap();
+    
+    let output = run_rusky_command(&["import", "husky"]);
+    assert!(output.status.success());
+    
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Imported pre-commit: echo 'from husky' > husky-ran.txt"));
+    assert!(stdout.contains("Unset core.hooksPath (.husky/_)"));
+    
+    // husky의 core.hooksPath가 해제되고 rusky hook이 설치됨
+    let hooks_path = Command::new("git")
+        .args(["config", "core.hooksPath"])
+        .output()
+        .unwrap();
+    assert!(!hooks_path.status.success());
+    assert!(std::path::Path::new(".git/hooks/pre-commit").exists());
+    
+    // 다시 가져와도 단계가 중복되지 않음
+    let output = run_rusky_command(&["import", "husky"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("pre-commit is already configured"));
+    
+    let output = run_rusky_command(&["list"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("husky-ran.txt").count(), 1);
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_import_pre_commit() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    std::fs::write(
+        ".pre-commit-config.yaml",
+        "repos:\n  - repo: https://github.com/pre-commit/pre-commit-hooks\n    rev: v4.5.0\n    hooks:\n      - id: check-json\n  - repo: local\n    hooks:\n      - id: lint\n        entry: echo lint\n        language: system\n        files: \\.rs$\n",
+    )
+    .unwrap();
+    std::fs::write(".git/hooks/pre-commit", "#!/bin/sh\n# File generated by pre-commit\n").unwrap();
+    
+    let output = run_rusky_command(&["import", "pre-commit"]);
+    assert!(output.status.success());
+    
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Imported pre-commit: builtin syntax, echo lint [*.rs]"));
+    assert!(stdout.contains("Removed pre-commit's pre-commit hook"));
+    
+    // pre-commit의 hook 대신 rusky hook이 설치됨
+    let hook = std::fs::read_to_string(".git/hooks/pre-commit").unwrap();
+    assert!(!hook.contains("File generated by pre-commit"));
+    
+    cleanup_test_env(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_rusky_list_is_sorted() {
+    let (_temp_dir, original_dir) = setup_test_env();
+    
+    run_rusky_command(&["init"]);
+    run_rusky_command(&["add", "pre-push", "echo 'pre-push hook'"]);
+    run_rusky_command(&["add", "commit-msg", "echo 'commit-msg hook'"]);
+    run_rusky_command(&["add", "pre-commit", "echo 'pre-commit hook'"]);
+    
+    let output = run_rusky_command(&["list"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let commit_msg = stdout.find("commit-msg:").unwrap();
+    let pre_commit = stdout.find("pre-commit:").unwrap();
+    let pre_push = stdout.find("pre-push:").unwrap();
+    assert!(commit_msg < pre_commit && pre_commit < pre_push);
+    
+    // 설정 파일도 정렬된 순서로 저장됨
+    let config = std::fs::read_to_string(".rusky/config.json").unwrap();
+    assert!(config.find("commit-msg").unwrap() < config.find("pre-push").unwrap());
+    assert!(config.ends_with('\n'));
+    
+    cleanup_test_env(original_dir);
+}
+
 #[test]
 #[serial]
 fn test_rusky_error_handling() {
@@ -219,6 +596,46 @@ fn test_rusky_version() {
     cleanup_test_env(original_dir);
 }
 
+#[test]
+fn test_rusky_with_git_dir_env() {
+    // 테스트 프로세스의 환경 변수는 바꾸지 않고 rusky에만 GIT_DIR/GIT_WORK_TREE 전달
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    let (meta, work, elsewhere) = (root.join("meta"), root.join("work"), root.join("elsewhere"));
+    Command::new("git")
+        .args(["init", "-q", "--separate-git-dir"])
+        .arg(&meta)
+        .arg(&work)
+        .output()
+        .unwrap();
+    std::fs::create_dir(&elsewhere).unwrap();
+
+    let cargo_bin = env::var("CARGO_BIN_EXE_rusky")
+        .unwrap_or_else(|_| "./target/debug/rusky".to_string());
+    let rusky = |dir: &std::path::Path, args: &[&str]| {
+        Command::new(&cargo_bin)
+            .args(args)
+            .current_dir(dir)
+            .env("GIT_DIR", &meta)
+            .env("GIT_WORK_TREE", &work)
+            .output()
+            .unwrap()
+    };
+
+    assert!(rusky(&work, &["init"]).status.success());
+    assert!(rusky(&work, &["add", "pre-commit", "pwd > ../pwd.txt"]).status.success());
+    assert!(meta.join("hooks").join("pre-commit").exists());
+    assert!(!work.join(".git").join("hooks").exists());
+
+    // 저장소 밖에서 실행해도 GIT_WORK_TREE를 저장소 루트로 사용
+    let output = rusky(&elsewhere, &["run", "pre-commit"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(
+        std::fs::read_to_string(root.join("pwd.txt")).unwrap().trim(),
+        work.to_string_lossy()
+    );
+}
+
 #[test]
 #[serial]
 fn test_rusky_help() {